@@ -0,0 +1,18 @@
+use std::env;
+use std::path::PathBuf;
+
+// Generates the C header from the `extern "C"` functions of the crate into `OUT_DIR`. A copy is
+// committed to `include/` so non-Rust consumers don't need cbindgen, and a test fails if it
+// differs from the generated one.
+fn main() {
+    let crate_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let config = cbindgen::Config::from_file(crate_dir.join("cbindgen.toml"))
+        .expect("Failed to read cbindgen.toml");
+    cbindgen::generate_with_config(&crate_dir, config)
+        .expect("Failed to generate C header")
+        .write_to_file(out_dir.join("fuel_message_predicate.h"));
+
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+}
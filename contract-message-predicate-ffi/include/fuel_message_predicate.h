@@ -0,0 +1,114 @@
+#ifndef FUEL_MESSAGE_PREDICATE_H
+#define FUEL_MESSAGE_PREDICATE_H
+
+/* Generated by cbindgen from contract-message-predicate-ffi. Do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Length in bytes of hashes, roots and contract IDs
+ */
+#define FUEL_HASH_LEN 32
+
+/**
+ * Result of the functions writing to caller-supplied memory
+ */
+typedef enum FuelStatus {
+  FUEL_STATUS_OK = 0,
+  FUEL_STATUS_NULL_POINTER = 1,
+} FuelStatus;
+
+/**
+ * Consensus parameters of the chain, mirroring `fuel_tx::ConsensusParameters`
+ */
+typedef struct FuelConsensusParameters {
+  uint64_t contract_max_size;
+  uint64_t max_inputs;
+  uint64_t max_outputs;
+  uint64_t max_witnesses;
+  uint64_t max_gas_per_tx;
+  uint64_t max_script_length;
+  uint64_t max_script_data_length;
+  uint64_t max_storage_slots;
+  uint64_t max_predicate_length;
+  uint64_t max_predicate_data_length;
+  uint64_t gas_price_factor;
+  uint64_t gas_per_byte;
+  uint64_t max_message_data_length;
+  uint64_t chain_id;
+} FuelConsensusParameters;
+
+/**
+ * Bytes allocated by this library. The caller owns the buffer and must release it with
+ * `fuel_buffer_free` exactly once.
+ */
+typedef struct FuelBuffer {
+  uint8_t *data;
+  size_t len;
+} FuelBuffer;
+
+/**
+ * Gets the default consensus parameters of fuel-tx, to be adjusted by the caller
+ */
+struct FuelConsensusParameters fuel_consensus_parameters_default(void);
+
+/**
+ * Gets the bytecode of the message-to-contract predicate. Free with `fuel_buffer_free`.
+ */
+struct FuelBuffer fuel_predicate_bytecode(void);
+
+/**
+ * Gets the bytecode of the message-to-contract script. Free with `fuel_buffer_free`.
+ */
+struct FuelBuffer fuel_script_bytecode(void);
+
+/**
+ * Writes the hash of the message-to-contract script to `out`.
+ *
+ * # Safety
+ *
+ * `out` must be null or valid for writes of `FUEL_HASH_LEN` bytes.
+ */
+enum FuelStatus fuel_script_hash(uint8_t *out);
+
+/**
+ * Writes the root of the message-to-contract predicate on the chain with the given consensus
+ * parameters to `out`.
+ *
+ * # Safety
+ *
+ * `params` must be null or point to valid consensus parameters, and `out` must be null or
+ * valid for writes of `FUEL_HASH_LEN` bytes.
+ */
+enum FuelStatus fuel_predicate_root(const struct FuelConsensusParameters *params, uint8_t *out);
+
+/**
+ * Builds the data of a message-to-contract message, the target contract ID followed by the
+ * receiver payload, into `out`. Free the buffer with `fuel_buffer_free`.
+ *
+ * # Safety
+ *
+ * `contract_id` must be null or valid for reads of `FUEL_HASH_LEN` bytes, `payload` must be
+ * valid for reads of `payload_len` bytes (it may be null if `payload_len` is 0), and `out` must
+ * be null or valid for writes of a `FuelBuffer`.
+ */
+enum FuelStatus fuel_message_data(const uint8_t *contract_id,
+                                  const uint8_t *payload,
+                                  size_t payload_len,
+                                  struct FuelBuffer *out);
+
+/**
+ * Releases a buffer returned by this library. Freeing a buffer with a null `data` pointer does
+ * nothing.
+ *
+ * # Safety
+ *
+ * `buffer` must have been returned by this library and not freed before.
+ */
+void fuel_buffer_free(struct FuelBuffer buffer);
+
+#endif /* FUEL_MESSAGE_PREDICATE_H */
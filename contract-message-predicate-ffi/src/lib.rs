@@ -0,0 +1,197 @@
+// C ABI exposing the script and predicate of the `fuel-contract-message-predicate` crate, for
+// relayers not written in Rust. The header in `include/` is generated from this file by cbindgen,
+// which copies the `///` comments into it.
+
+use std::{ptr, slice};
+
+use fuel_tx::{ConsensusParameters, Input};
+
+/// Length in bytes of hashes, roots and contract IDs
+pub const FUEL_HASH_LEN: usize = 32;
+
+/// Result of the functions writing to caller-supplied memory
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FuelStatus {
+    Ok = 0,
+    NullPointer = 1,
+}
+
+/// Bytes allocated by this library. The caller owns the buffer and must release it with
+/// `fuel_buffer_free` exactly once.
+#[repr(C)]
+#[derive(Debug)]
+pub struct FuelBuffer {
+    pub data: *mut u8,
+    pub len: usize,
+}
+
+impl FuelBuffer {
+    fn from_vec(bytes: Vec<u8>) -> Self {
+        let bytes = Box::leak(bytes.into_boxed_slice());
+        FuelBuffer {
+            data: bytes.as_mut_ptr(),
+            len: bytes.len(),
+        }
+    }
+}
+
+/// Consensus parameters of the chain, mirroring `fuel_tx::ConsensusParameters`
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct FuelConsensusParameters {
+    pub contract_max_size: u64,
+    pub max_inputs: u64,
+    pub max_outputs: u64,
+    pub max_witnesses: u64,
+    pub max_gas_per_tx: u64,
+    pub max_script_length: u64,
+    pub max_script_data_length: u64,
+    pub max_storage_slots: u64,
+    pub max_predicate_length: u64,
+    pub max_predicate_data_length: u64,
+    pub gas_price_factor: u64,
+    pub gas_per_byte: u64,
+    pub max_message_data_length: u64,
+    pub chain_id: u64,
+}
+
+impl From<ConsensusParameters> for FuelConsensusParameters {
+    fn from(cparams: ConsensusParameters) -> Self {
+        FuelConsensusParameters {
+            contract_max_size: cparams.contract_max_size,
+            max_inputs: cparams.max_inputs,
+            max_outputs: cparams.max_outputs,
+            max_witnesses: cparams.max_witnesses,
+            max_gas_per_tx: cparams.max_gas_per_tx,
+            max_script_length: cparams.max_script_length,
+            max_script_data_length: cparams.max_script_data_length,
+            max_storage_slots: cparams.max_storage_slots,
+            max_predicate_length: cparams.max_predicate_length,
+            max_predicate_data_length: cparams.max_predicate_data_length,
+            gas_price_factor: cparams.gas_price_factor,
+            gas_per_byte: cparams.gas_per_byte,
+            max_message_data_length: cparams.max_message_data_length,
+            chain_id: cparams.chain_id,
+        }
+    }
+}
+
+impl From<FuelConsensusParameters> for ConsensusParameters {
+    fn from(params: FuelConsensusParameters) -> Self {
+        ConsensusParameters {
+            contract_max_size: params.contract_max_size,
+            max_inputs: params.max_inputs,
+            max_outputs: params.max_outputs,
+            max_witnesses: params.max_witnesses,
+            max_gas_per_tx: params.max_gas_per_tx,
+            max_script_length: params.max_script_length,
+            max_script_data_length: params.max_script_data_length,
+            max_storage_slots: params.max_storage_slots,
+            max_predicate_length: params.max_predicate_length,
+            max_predicate_data_length: params.max_predicate_data_length,
+            gas_price_factor: params.gas_price_factor,
+            gas_per_byte: params.gas_per_byte,
+            max_message_data_length: params.max_message_data_length,
+            chain_id: params.chain_id,
+        }
+    }
+}
+
+/// Gets the default consensus parameters of fuel-tx, to be adjusted by the caller
+#[no_mangle]
+pub extern "C" fn fuel_consensus_parameters_default() -> FuelConsensusParameters {
+    ConsensusParameters::DEFAULT.into()
+}
+
+/// Gets the bytecode of the message-to-contract predicate. Free with `fuel_buffer_free`.
+#[no_mangle]
+pub extern "C" fn fuel_predicate_bytecode() -> FuelBuffer {
+    FuelBuffer::from_vec(fuel_contract_message_predicate::predicate_bytecode())
+}
+
+/// Gets the bytecode of the message-to-contract script. Free with `fuel_buffer_free`.
+#[no_mangle]
+pub extern "C" fn fuel_script_bytecode() -> FuelBuffer {
+    FuelBuffer::from_vec(fuel_contract_message_predicate::script_bytecode())
+}
+
+/// Writes the hash of the message-to-contract script to `out`.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes of `FUEL_HASH_LEN` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn fuel_script_hash(out: *mut u8) -> FuelStatus {
+    if out.is_null() {
+        return FuelStatus::NullPointer;
+    }
+    let hash = fuel_contract_message_predicate::script_hash();
+    ptr::copy_nonoverlapping(hash.as_ptr(), out, FUEL_HASH_LEN);
+    FuelStatus::Ok
+}
+
+/// Writes the root of the message-to-contract predicate on the chain with the given consensus
+/// parameters to `out`.
+///
+/// # Safety
+///
+/// `params` must be null or point to valid consensus parameters, and `out` must be null or
+/// valid for writes of `FUEL_HASH_LEN` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn fuel_predicate_root(
+    params: *const FuelConsensusParameters,
+    out: *mut u8,
+) -> FuelStatus {
+    if params.is_null() || out.is_null() {
+        return FuelStatus::NullPointer;
+    }
+    let cparams: ConsensusParameters = (*params).into();
+    let predicate = fuel_contract_message_predicate::predicate_bytecode();
+    let root = Input::predicate_owner(predicate, &cparams);
+    ptr::copy_nonoverlapping(root.as_ptr(), out, FUEL_HASH_LEN);
+    FuelStatus::Ok
+}
+
+/// Builds the data of a message-to-contract message, the target contract ID followed by the
+/// receiver payload, into `out`. Free the buffer with `fuel_buffer_free`.
+///
+/// # Safety
+///
+/// `contract_id` must be null or valid for reads of `FUEL_HASH_LEN` bytes, `payload` must be
+/// valid for reads of `payload_len` bytes (it may be null if `payload_len` is 0), and `out` must
+/// be null or valid for writes of a `FuelBuffer`.
+#[no_mangle]
+pub unsafe extern "C" fn fuel_message_data(
+    contract_id: *const u8,
+    payload: *const u8,
+    payload_len: usize,
+    out: *mut FuelBuffer,
+) -> FuelStatus {
+    if contract_id.is_null() || out.is_null() || (payload.is_null() && payload_len > 0) {
+        return FuelStatus::NullPointer;
+    }
+    let contract_id = &*(contract_id as *const [u8; FUEL_HASH_LEN]);
+    let payload = match payload_len {
+        0 => &[][..],
+        _ => slice::from_raw_parts(payload, payload_len),
+    };
+    let data = fuel_contract_message_predicate::message_data(contract_id, payload);
+    out.write(FuelBuffer::from_vec(data));
+    FuelStatus::Ok
+}
+
+/// Releases a buffer returned by this library. Freeing a buffer with a null `data` pointer does
+/// nothing.
+///
+/// # Safety
+///
+/// `buffer` must have been returned by this library and not freed before.
+#[no_mangle]
+pub unsafe extern "C" fn fuel_buffer_free(buffer: FuelBuffer) {
+    if buffer.data.is_null() {
+        return;
+    }
+    let bytes = slice::from_raw_parts_mut(buffer.data, buffer.len);
+    drop(Box::from_raw(bytes));
+}
@@ -0,0 +1,102 @@
+// Exercises the C ABI the way a non-Rust relayer would. Exits with a non-zero status on the
+// first failed check. Takes the expected values of the shared bindings fixture as arguments:
+// SCRIPT_HASH PREDICATE_ROOT PREDICATE_ROOT_CHAIN_ID_1 MESSAGE_DATA_CONTRACT_ID
+// MESSAGE_DATA_PAYLOAD MESSAGE_DATA
+
+#include <stdio.h>
+#include <string.h>
+
+#include "fuel_message_predicate.h"
+
+#define CHECK(cond)                                                 \
+  do {                                                              \
+    if (!(cond)) {                                                  \
+      fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__,        \
+              __LINE__, #cond);                                     \
+      return 1;                                                     \
+    }                                                               \
+  } while (0)
+
+#define MAX_DATA_LEN 256
+
+static void to_hex(const uint8_t *bytes, size_t len, char *out) {
+  for (size_t i = 0; i < len; i++) {
+    sprintf(out + 2 * i, "%02x", bytes[i]);
+  }
+}
+
+// Decodes a hex string into at most `max_len` bytes, returning the number of bytes or -1
+static int from_hex(const char *hex, uint8_t *out, size_t max_len) {
+  size_t len = strlen(hex) / 2;
+  if (strlen(hex) % 2 != 0 || len > max_len) {
+    return -1;
+  }
+  for (size_t i = 0; i < len; i++) {
+    unsigned int byte;
+    if (sscanf(hex + 2 * i, "%2x", &byte) != 1) {
+      return -1;
+    }
+    out[i] = (uint8_t)byte;
+  }
+  return (int)len;
+}
+
+int main(int argc, char **argv) {
+  CHECK(argc == 7);
+  const char *SCRIPT_HASH = argv[1];
+  const char *PREDICATE_ROOT = argv[2];
+  const char *PREDICATE_ROOT_CHAIN_ID_1 = argv[3];
+  const char *MESSAGE_DATA = argv[6];
+  char hex[2 * MAX_DATA_LEN + 1];
+
+  uint8_t script_hash[FUEL_HASH_LEN];
+  CHECK(fuel_script_hash(script_hash) == FUEL_STATUS_OK);
+  to_hex(script_hash, FUEL_HASH_LEN, hex);
+  CHECK(strcmp(hex, SCRIPT_HASH) == 0);
+  CHECK(fuel_script_hash(NULL) == FUEL_STATUS_NULL_POINTER);
+
+  // The predicate ends with the hash of the script it requires
+  FuelBuffer predicate = fuel_predicate_bytecode();
+  CHECK(predicate.data != NULL && predicate.len > FUEL_HASH_LEN);
+  CHECK(memcmp(predicate.data + predicate.len - FUEL_HASH_LEN, script_hash, FUEL_HASH_LEN) == 0);
+  fuel_buffer_free(predicate);
+
+  FuelBuffer script = fuel_script_bytecode();
+  CHECK(script.data != NULL && script.len > 0);
+  fuel_buffer_free(script);
+
+  uint8_t root[FUEL_HASH_LEN];
+  FuelConsensusParameters params = fuel_consensus_parameters_default();
+  CHECK(fuel_predicate_root(&params, root) == FUEL_STATUS_OK);
+  to_hex(root, FUEL_HASH_LEN, hex);
+  CHECK(strcmp(hex, PREDICATE_ROOT) == 0);
+  params.chain_id = 1;
+  CHECK(fuel_predicate_root(&params, root) == FUEL_STATUS_OK);
+  to_hex(root, FUEL_HASH_LEN, hex);
+  CHECK(strcmp(hex, PREDICATE_ROOT_CHAIN_ID_1) == 0);
+  CHECK(fuel_predicate_root(NULL, root) == FUEL_STATUS_NULL_POINTER);
+
+  uint8_t contract_id[FUEL_HASH_LEN];
+  CHECK(from_hex(argv[4], contract_id, FUEL_HASH_LEN) == FUEL_HASH_LEN);
+  uint8_t payload[MAX_DATA_LEN - FUEL_HASH_LEN];
+  int payload_len = from_hex(argv[5], payload, sizeof(payload));
+  CHECK(payload_len >= 0);
+  FuelBuffer data;
+  CHECK(fuel_message_data(contract_id, payload, payload_len, &data) == FUEL_STATUS_OK);
+  CHECK(data.len == FUEL_HASH_LEN + (size_t)payload_len);
+  to_hex(data.data, data.len, hex);
+  CHECK(strcmp(hex, MESSAGE_DATA) == 0);
+  fuel_buffer_free(data);
+
+  CHECK(fuel_message_data(contract_id, NULL, 0, &data) == FUEL_STATUS_OK);
+  CHECK(data.len == FUEL_HASH_LEN);
+  fuel_buffer_free(data);
+  CHECK(fuel_message_data(contract_id, NULL, 1, &data) == FUEL_STATUS_NULL_POINTER);
+  CHECK(fuel_message_data(NULL, payload, payload_len, &data) == FUEL_STATUS_NULL_POINTER);
+
+  FuelBuffer empty = {NULL, 0};
+  fuel_buffer_free(empty);
+
+  printf("all C ABI checks passed\n");
+  return 0;
+}
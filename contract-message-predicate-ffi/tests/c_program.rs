@@ -0,0 +1,72 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::{env, fs};
+
+// File name of the C header generated by the build script
+const HEADER_NAME: &str = "fuel_message_predicate.h";
+
+// Values computed by the Rust crate, shared with the Python and wasm binding tests
+const FIXTURE: &str = include_str!("../../contract-message-predicate/tests/fixtures/bindings.txt");
+
+// Keys of the fixture values the C test program takes as arguments, in order
+const FIXTURE_KEYS: [&str; 6] = [
+    "SCRIPT_HASH",
+    "PREDICATE_ROOT",
+    "PREDICATE_ROOT_CHAIN_ID_1",
+    "MESSAGE_DATA_CONTRACT_ID",
+    "MESSAGE_DATA_PAYLOAD",
+    "MESSAGE_DATA",
+];
+
+/// Gets the value of the given key in the bindings fixture
+fn expected(key: &str) -> &'static str {
+    FIXTURE
+        .lines()
+        .find_map(|line| line.strip_prefix(key)?.strip_prefix('='))
+        .unwrap_or_else(|| panic!("Missing {key} in the bindings fixture"))
+}
+
+// Compiles `tests/c/test_ffi.c` against the header generated by the build script and the cdylib
+// built for this test run, then runs it with the expected values of the bindings fixture. Uses
+// the compiler in `CC`, or `cc` by default.
+#[test]
+fn c_program_passes() {
+    let crate_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let header_dir = PathBuf::from(env!("OUT_DIR"));
+    // Test binaries and the cdylib they depend on are both built in `target/<profile>/deps`
+    let lib_dir = env::current_exe().unwrap().parent().unwrap().to_path_buf();
+    let program = lib_dir.join("test_ffi");
+
+    let compiler = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let status = Command::new(compiler)
+        .arg(crate_dir.join("tests/c/test_ffi.c"))
+        .arg("-I")
+        .arg(&header_dir)
+        .arg("-L")
+        .arg(&lib_dir)
+        .arg(format!("-Wl,-rpath,{}", lib_dir.display()))
+        .arg("-lfuel_message_predicate_ffi")
+        .arg("-Wall")
+        .arg("-Werror")
+        .arg("-o")
+        .arg(&program)
+        .status()
+        .expect("Failed to run the C compiler");
+    assert!(status.success(), "Failed to compile the C test program");
+
+    let status = Command::new(&program)
+        .args(FIXTURE_KEYS.map(expected))
+        .status()
+        .expect("Failed to run the C test program");
+    assert!(status.success(), "C test program failed");
+}
+
+// Ensure the committed header matches the one generated by the build script; copy
+// `$OUT_DIR/fuel_message_predicate.h` to `include/` if this fails
+#[test]
+fn committed_header_is_up_to_date() {
+    let crate_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let generated = fs::read_to_string(Path::new(env!("OUT_DIR")).join(HEADER_NAME)).unwrap();
+    let committed = fs::read_to_string(crate_dir.join("include").join(HEADER_NAME)).unwrap();
+    assert_eq!(committed, generated);
+}
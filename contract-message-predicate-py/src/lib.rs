@@ -0,0 +1,64 @@
+use fuel_tx::ConsensusParameters;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+// Gets the bytecode of the message-to-contract predicate
+#[pyfunction]
+fn predicate_bytecode(py: Python<'_>) -> &PyBytes {
+    PyBytes::new(py, &fuel_contract_message_predicate::predicate_bytecode())
+}
+
+// Gets the bytecode of the message-to-contract script
+#[pyfunction]
+fn script_bytecode(py: Python<'_>) -> &PyBytes {
+    PyBytes::new(py, &fuel_contract_message_predicate::script_bytecode())
+}
+
+// Gets the hash of the message-to-contract script
+#[pyfunction]
+fn script_hash(py: Python<'_>) -> &PyBytes {
+    PyBytes::new(py, &fuel_contract_message_predicate::script_hash())
+}
+
+// Gets the root of the message-to-contract predicate on the chain with the given ID. The root
+// only depends on the predicate bytecode and the chain ID of the consensus parameters, so the
+// other parameters are left at their defaults.
+#[pyfunction]
+#[pyo3(signature = (chain_id = 0))]
+fn predicate_root(py: Python<'_>, chain_id: u64) -> &PyBytes {
+    let cparams = ConsensusParameters {
+        chain_id,
+        ..ConsensusParameters::DEFAULT
+    };
+    PyBytes::new(
+        py,
+        &fuel_contract_message_predicate::predicate_root(&cparams),
+    )
+}
+
+// Builds the data of a message-to-contract message: the target contract ID followed by the
+// receiver payload
+#[pyfunction]
+fn message_data<'py>(
+    py: Python<'py>,
+    contract_id: &[u8],
+    payload: &[u8],
+) -> PyResult<&'py PyBytes> {
+    let contract_id: &[u8; 32] = contract_id
+        .try_into()
+        .map_err(|_| PyValueError::new_err("contract ID must be 32 bytes"))?;
+    let data = fuel_contract_message_predicate::message_data(contract_id, payload);
+    Ok(PyBytes::new(py, &data))
+}
+
+// Python module exposing the script and predicate of the `fuel-contract-message-predicate` crate
+#[pymodule]
+fn fuel_message_predicate(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(predicate_bytecode, m)?)?;
+    m.add_function(wrap_pyfunction!(script_bytecode, m)?)?;
+    m.add_function(wrap_pyfunction!(script_hash, m)?)?;
+    m.add_function(wrap_pyfunction!(predicate_root, m)?)?;
+    m.add_function(wrap_pyfunction!(message_data, m)?)?;
+    Ok(())
+}
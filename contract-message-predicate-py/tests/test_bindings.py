@@ -0,0 +1,51 @@
+import unittest
+from pathlib import Path
+
+import fuel_message_predicate as predicate
+
+# Values computed by the Rust crate, shared with the wasm and C binding tests
+FIXTURE_PATH = (
+    Path(__file__).resolve().parents[2]
+    / "contract-message-predicate"
+    / "tests"
+    / "fixtures"
+    / "bindings.txt"
+)
+
+
+def load_fixture(path):
+    lines = path.read_text().splitlines()
+    entries = [line.split("=", 1) for line in lines if line and not line.startswith("#")]
+    return {key: value for key, value in entries}
+
+
+EXPECTED = load_fixture(FIXTURE_PATH)
+
+
+class BindingsTest(unittest.TestCase):
+    def test_script_hash(self):
+        self.assertEqual(predicate.script_hash().hex(), EXPECTED["SCRIPT_HASH"])
+
+    def test_predicate_root(self):
+        self.assertEqual(predicate.predicate_root().hex(), EXPECTED["PREDICATE_ROOT"])
+        self.assertEqual(predicate.predicate_root(0).hex(), EXPECTED["PREDICATE_ROOT"])
+        self.assertEqual(
+            predicate.predicate_root(chain_id=1).hex(), EXPECTED["PREDICATE_ROOT_CHAIN_ID_1"]
+        )
+
+    def test_predicate_commits_to_script(self):
+        bytecode = predicate.predicate_bytecode()
+        self.assertEqual(bytecode[-32:], predicate.script_hash())
+        self.assertGreater(len(predicate.script_bytecode()), 0)
+
+    def test_message_data(self):
+        contract_id = bytes.fromhex(EXPECTED["MESSAGE_DATA_CONTRACT_ID"])
+        payload = bytes.fromhex(EXPECTED["MESSAGE_DATA_PAYLOAD"])
+        data = predicate.message_data(contract_id, payload)
+        self.assertEqual(data.hex(), EXPECTED["MESSAGE_DATA"])
+        with self.assertRaises(ValueError):
+            predicate.message_data(contract_id[:31], b"")
+
+
+if __name__ == "__main__":
+    unittest.main()
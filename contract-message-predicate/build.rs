@@ -0,0 +1,77 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::{env, fs};
+
+// Records the git commit and the resolved `fuel-asm` version for bytecode attestations
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+
+    //git commit of the source tree, if built from a git checkout, marked `-dirty` if the
+    //working tree has uncommitted changes
+    if let Some(commit) = git(&manifest_dir, &["rev-parse", "HEAD"]) {
+        let dirty = git(&manifest_dir, &["status", "--porcelain"])
+            .map_or(false, |status| !status.is_empty());
+        let suffix = if dirty { "-dirty" } else { "" };
+        println!("cargo:rustc-env=MESSAGE_PREDICATE_GIT_COMMIT={commit}{suffix}");
+    }
+    if let Some(git_dir) = git(&manifest_dir, &["rev-parse", "--absolute-git-dir"]) {
+        println!("cargo:rerun-if-changed={git_dir}/HEAD");
+        println!("cargo:rerun-if-changed={git_dir}/logs/HEAD");
+        println!("cargo:rerun-if-changed={git_dir}/index");
+    }
+
+    //version of `fuel-asm` the bytecode is assembled with, from the lock file
+    let lock_path = manifest_dir
+        .ancestors()
+        .map(|dir| dir.join("Cargo.lock"))
+        .find(|path| path.exists());
+    let fuel_asm_version = lock_path
+        .as_deref()
+        .and_then(fuel_asm_version)
+        .unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=MESSAGE_PREDICATE_FUEL_ASM_VERSION={fuel_asm_version}");
+    if let Some(lock_path) = lock_path {
+        println!("cargo:rerun-if-changed={}", lock_path.display());
+    }
+    println!("cargo:rerun-if-changed=build.rs");
+}
+
+fn git(dir: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .ok()?;
+    match output.status.success() {
+        true => Some(String::from_utf8(output.stdout).ok()?.trim().to_string()),
+        false => None,
+    }
+}
+
+// Finds the `fuel-asm` dependency of this crate in the lock file. The lock file lists it as
+// `fuel-asm <version>` if several versions are locked and as `fuel-asm` otherwise.
+fn fuel_asm_version(lock_path: &Path) -> Option<String> {
+    let lock = fs::read_to_string(lock_path).ok()?;
+    let packages: Vec<&str> = lock.split("[[package]]").collect();
+    let field = |package: &str, key: &str| -> Option<String> {
+        package
+            .lines()
+            .find_map(|line| line.strip_prefix(&format!("{key} = \"")))
+            .map(|value| value.trim_end_matches('"').to_string())
+    };
+
+    let this_package = packages
+        .iter()
+        .find(|p| field(p, "name").as_deref() == Some(env!("CARGO_PKG_NAME")))?;
+    let dependency = this_package
+        .lines()
+        .map(|line| line.trim().trim_matches(|c| c == '"' || c == ','))
+        .find(|dep| *dep == "fuel-asm" || dep.starts_with("fuel-asm "))?;
+    match dependency.strip_prefix("fuel-asm ") {
+        Some(version) => Some(version.to_string()),
+        None => packages
+            .iter()
+            .find(|p| field(p, "name").as_deref() == Some("fuel-asm"))
+            .and_then(|p| field(p, "version")),
+    }
+}
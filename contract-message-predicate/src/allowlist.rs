@@ -0,0 +1,165 @@
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "std")]
+use fuel_tx::ConsensusParameters;
+use sha2::{Digest, Sha256};
+
+use crate::predicate_asm;
+
+// Set of contract IDs that messages sent to an allowlist predicate may target.
+// The predicate embeds the Merkle root of the set and the relayer passes a proof for the
+// target contract as predicate data. Leaves are the SHA-256 hashes of the contract IDs,
+// padded with zeroed leaves to a power of two, and nodes are the SHA-256 hash of their
+// left and right children.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractAllowlist {
+    contract_ids: Vec<[u8; 32]>,
+    levels: Vec<Vec<[u8; 32]>>,
+}
+
+// Proof that a contract ID is in a `ContractAllowlist`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AllowlistProof {
+    pub index: u64,
+    pub siblings: Vec<[u8; 32]>,
+}
+
+impl ContractAllowlist {
+    // Builds the allowlist of the given contract IDs
+    pub fn new(contract_ids: &[[u8; 32]]) -> Self {
+        let mut levels = vec![];
+        if !contract_ids.is_empty() {
+            let mut leaves: Vec<[u8; 32]> = contract_ids.iter().map(leaf_hash).collect();
+            leaves.resize(contract_ids.len().next_power_of_two(), [0u8; 32]);
+            levels.push(leaves);
+            while levels[levels.len() - 1].len() > 1 {
+                let nodes = levels[levels.len() - 1]
+                    .chunks_exact(2)
+                    .map(|pair| node_hash(&pair[0], &pair[1]))
+                    .collect();
+                levels.push(nodes);
+            }
+        }
+
+        ContractAllowlist {
+            contract_ids: contract_ids.to_vec(),
+            levels,
+        }
+    }
+
+    // Gets the contract IDs in the allowlist
+    pub fn contract_ids(&self) -> &[[u8; 32]] {
+        &self.contract_ids
+    }
+
+    // Gets the Merkle root of the allowlist (zeroed for an empty allowlist)
+    pub fn root(&self) -> [u8; 32] {
+        match self.levels.last() {
+            Some(level) => level[0],
+            None => [0u8; 32],
+        }
+    }
+
+    // Gets the proof for the given contract ID, if it is in the allowlist
+    pub fn proof(&self, contract_id: &[u8; 32]) -> Option<AllowlistProof> {
+        let index = self.contract_ids.iter().position(|id| id == contract_id)?;
+        let siblings = self.levels[..self.levels.len() - 1]
+            .iter()
+            .enumerate()
+            .map(|(depth, level)| level[(index >> depth) ^ 1])
+            .collect();
+        Some(AllowlistProof {
+            index: index as u64,
+            siblings,
+        })
+    }
+
+    // Gets the bytecode of the allowlist predicate for this allowlist
+    pub fn predicate_bytecode(&self) -> Vec<u8> {
+        predicate_asm::allowlist_bytecode_for_script(crate::script_hash(), self.root())
+    }
+
+    // Gets the root of the allowlist predicate for this allowlist
+    #[cfg(feature = "std")]
+    pub fn predicate_root(&self, cparams: &ConsensusParameters) -> [u8; 32] {
+        crate::root(&self.predicate_bytecode(), cparams)
+    }
+}
+
+impl AllowlistProof {
+    // Checks the proof for the given contract ID against an allowlist root
+    pub fn verify(&self, root: &[u8; 32], contract_id: &[u8; 32]) -> bool {
+        let mut node = leaf_hash(contract_id);
+        for (depth, sibling) in self.siblings.iter().enumerate() {
+            node = match (self.index >> depth) & 1 {
+                0 => node_hash(&node, sibling),
+                _ => node_hash(sibling, &node),
+            };
+        }
+        &node == root
+    }
+
+    // Encodes the proof as predicate data for the allowlist predicate:
+    // the number of siblings and the leaf index as words, followed by the siblings
+    pub fn to_predicate_data(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(8 + 8 + self.siblings.len() * 32);
+        data.extend_from_slice(&(self.siblings.len() as u64).to_be_bytes());
+        data.extend_from_slice(&self.index.to_be_bytes());
+        for sibling in &self.siblings {
+            data.extend_from_slice(sibling);
+        }
+        data
+    }
+}
+
+fn leaf_hash(contract_id: &[u8; 32]) -> [u8; 32] {
+    crate::sha256(contract_id)
+}
+
+fn node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contract_ids(count: u8) -> Vec<[u8; 32]> {
+        (1..=count).map(|i| [i; 32]).collect()
+    }
+
+    #[test]
+    fn proves_allowed_contracts() {
+        for count in 1..=5 {
+            let ids = contract_ids(count);
+            let allowlist = ContractAllowlist::new(&ids);
+            for id in &ids {
+                let proof = allowlist.proof(id).unwrap();
+                assert!(proof.verify(&allowlist.root(), id));
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_disallowed_contracts() {
+        let allowlist = ContractAllowlist::new(&contract_ids(3));
+        assert_eq!(allowlist.proof(&[9u8; 32]), None);
+
+        let proof = allowlist.proof(&[1u8; 32]).unwrap();
+        assert!(!proof.verify(&allowlist.root(), &[9u8; 32]));
+        assert!(!proof.verify(&allowlist.root(), &[2u8; 32]));
+    }
+
+    #[test]
+    fn encodes_predicate_data() {
+        let allowlist = ContractAllowlist::new(&contract_ids(3));
+        let proof = allowlist.proof(&[3u8; 32]).unwrap();
+        let data = proof.to_predicate_data();
+        assert_eq!(data.len(), 8 + 8 + 2 * 32);
+        assert_eq!(data[0..8], 2u64.to_be_bytes());
+        assert_eq!(data[8..16], 2u64.to_be_bytes());
+        assert_eq!(data[16..48], proof.siblings[0]);
+    }
+}
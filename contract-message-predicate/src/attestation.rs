@@ -0,0 +1,157 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::manifest::{self, hex_string, Network};
+
+// Git commit and `fuel-asm` version recorded by the build script
+const GIT_COMMIT: Option<&str> = option_env!("MESSAGE_PREDICATE_GIT_COMMIT");
+const FUEL_ASM_VERSION: &str = env!("MESSAGE_PREDICATE_FUEL_ASM_VERSION");
+
+// Deterministic record of how the script and predicate bytecode was produced, so reviewers can
+// rebuild the bytecode from the same crate version and compare. Bytecode hashes are the SHA-256
+// of the binaries written by the build, keyed by binary name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attestation {
+    pub crate_version: String,
+    pub git_commit: Option<String>,
+    pub fuel_asm_version: String,
+    pub networks: Vec<Network>,
+    pub bytecode_hashes: BTreeMap<String, String>,
+    pub predicate_roots: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl Attestation {
+    // Builds the attestation of the compiled-in bytecode for the given networks
+    pub fn build(networks: &[Network]) -> Self {
+        let scripts = manifest::script_artifacts();
+        let predicates = manifest::predicate_artifacts();
+        Attestation {
+            crate_version: env!("CARGO_PKG_VERSION").to_string(),
+            git_commit: GIT_COMMIT.map(str::to_string),
+            fuel_asm_version: FUEL_ASM_VERSION.to_string(),
+            networks: networks.to_vec(),
+            bytecode_hashes: scripts
+                .iter()
+                .chain(predicates.iter())
+                .map(|(name, bytecode)| (name.to_string(), hex_string(&crate::sha256(bytecode))))
+                .collect(),
+            predicate_roots: predicates
+                .iter()
+                .map(|(name, predicate)| {
+                    let roots = manifest::predicate_roots(predicate, networks);
+                    (name.to_string(), roots)
+                })
+                .collect(),
+        }
+    }
+
+    // Parses an attestation from JSON
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    // Serializes the attestation to pretty-printed JSON
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Failed to serialize attestation")
+    }
+
+    // Rebuilds the bytecode for the networks of the attestation and checks every field,
+    // returning the names of the fields that differ. The git commit is only compared if both
+    // the attestation and this build record one.
+    pub fn check(&self) -> Result<(), Vec<String>> {
+        let expected = Self::build(&self.networks);
+        let mut mismatches = vec![];
+        if self.crate_version != expected.crate_version {
+            mismatches.push("crate_version".to_string());
+        }
+        if git_commits_differ(&self.git_commit, &expected.git_commit) {
+            mismatches.push("git_commit".to_string());
+        }
+        if self.fuel_asm_version != expected.fuel_asm_version {
+            mismatches.push("fuel_asm_version".to_string());
+        }
+        mismatches.extend(manifest::diff_keys(
+            "bytecode_hashes",
+            &self.bytecode_hashes,
+            &expected.bytecode_hashes,
+        ));
+        mismatches.extend(manifest::diff_keys(
+            "predicate_roots",
+            &self.predicate_roots,
+            &expected.predicate_roots,
+        ));
+
+        match mismatches.is_empty() {
+            true => Ok(()),
+            false => Err(mismatches),
+        }
+    }
+}
+
+// Compares two recorded git commits, treating a commit missing from either side as unknown
+// rather than a mismatch
+fn git_commits_differ(commit: &Option<String>, expected: &Option<String>) -> bool {
+    match (commit, expected) {
+        (Some(commit), Some(expected)) => commit != expected,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attestation_is_deterministic_and_checks() {
+        let attestation = Attestation::build(&Network::configured());
+        assert_eq!(
+            attestation.to_json(),
+            Attestation::build(&Network::configured()).to_json()
+        );
+
+        let parsed = Attestation::from_json(&attestation.to_json()).unwrap();
+        assert_eq!(parsed, attestation);
+        assert_eq!(parsed.check(), Ok(()));
+        assert_eq!(
+            parsed.bytecode_hashes["contract_message_script"],
+            hex_string(&crate::script_hash())
+        );
+    }
+
+    #[test]
+    fn check_ignores_unknown_git_commit() {
+        let mut attestation = Attestation::build(&Network::configured());
+        attestation.git_commit = None;
+        assert_eq!(attestation.check(), Ok(()));
+
+        let commit = Some("0000000".to_string());
+        let dirty_commit = Some("0000000-dirty".to_string());
+        assert!(!git_commits_differ(&commit, &commit));
+        assert!(!git_commits_differ(&commit, &None));
+        assert!(!git_commits_differ(&None, &commit));
+        assert!(git_commits_differ(&commit, &dirty_commit));
+    }
+
+    #[test]
+    fn check_reports_mismatched_fields() {
+        let mut attestation = Attestation::build(&Network::configured());
+        attestation.fuel_asm_version = "0.0.0".to_string();
+        attestation
+            .bytecode_hashes
+            .insert("contract_message_predicate".to_string(), "0x".to_string());
+        attestation
+            .predicate_roots
+            .get_mut("contract_message_fee_predicate")
+            .unwrap()
+            .insert("local".to_string(), "0x".to_string());
+        assert_eq!(
+            attestation.check(),
+            Err(vec![
+                "fuel_asm_version".to_string(),
+                "bytecode_hashes.contract_message_predicate".to_string(),
+                "predicate_roots.contract_message_fee_predicate".to_string(),
+            ])
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use alloc::vec::Vec;
+use sha2::{Digest, Sha256};
+
+// Seed of contract IDs and predicate owners ("FUEL")
+const CONTRACT_ID_SEED: [u8; 4] = 0x4655454C_u32.to_be_bytes();
+const WORD_SIZE: usize = 8;
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+// Gets the code root of the given bytecode, as computed by `fuel_tx::Contract::root_from_code`:
+// the root of a binary Merkle tree over the 8 byte words of the bytecode, with the last word
+// zero padded
+pub fn code_root(code: &[u8]) -> [u8; 32] {
+    let leaves: Vec<[u8; 32]> = code
+        .chunks(WORD_SIZE)
+        .map(|chunk| {
+            let mut word = [0u8; WORD_SIZE];
+            word[..chunk.len()].copy_from_slice(chunk);
+            let mut hasher = Sha256::new();
+            hasher.update([LEAF_PREFIX]);
+            hasher.update(word);
+            hasher.finalize().into()
+        })
+        .collect();
+    merkle_root(&leaves)
+}
+
+// Gets the code root of the contract binary at the given path, such as a contract built by forc
+#[cfg(feature = "std")]
+pub fn contract_code_root<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<[u8; 32]> {
+    let code = std::fs::read(path)?;
+    Ok(code_root(&code))
+}
+
+// Gets the owner address of the given predicate on the chain with the given ID, as computed by
+// `fuel_tx::Input::predicate_owner`, without depending on fuel-tx
+pub fn predicate_owner(predicate: &[u8], chain_id: u64) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(CONTRACT_ID_SEED);
+    hasher.update(chain_id.to_be_bytes());
+    hasher.update(code_root(predicate));
+    hasher.finalize().into()
+}
+
+// Merkle tree hash of the given leaf hashes (RFC 6962), splitting at the largest power of two
+fn merkle_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    match leaves.len() {
+        0 => Sha256::digest([]).into(),
+        1 => leaves[0],
+        len => {
+            let split = len.next_power_of_two() / 2;
+            let mut hasher = Sha256::new();
+            hasher.update([NODE_PREFIX]);
+            hasher.update(merkle_root(&leaves[..split]));
+            hasher.update(merkle_root(&leaves[split..]));
+            hasher.finalize().into()
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use fuel_tx::{ConsensusParameters, Contract, Input};
+
+    #[test]
+    fn matches_fuel_tx() {
+        let predicates = [
+            crate::predicate_bytecode(),
+            crate::refundable_predicate_bytecode(),
+            crate::time_locked_predicate_bytecode(),
+            crate::fee_predicate_bytecode(),
+            crate::sealed_outputs_predicate_bytecode(),
+            crate::relayer_predicate_bytecode(),
+            crate::relay_window_predicate_bytecode(),
+            crate::pinned_predicate_bytecode(),
+            crate::event_predicate_bytecode(),
+            crate::recoverable_predicate_bytecode(&[1u8; 32]),
+            crate::self_funded_predicate_bytecode(),
+        ];
+        for predicate in predicates {
+            for len in [0, 1, 7, 8, 9, 24, predicate.len()] {
+                let code = &predicate[..len];
+                assert_eq!(code_root(code), *Contract::root_from_code(code));
+            }
+            for chain_id in [0, 1, u64::MAX] {
+                let cparams = ConsensusParameters {
+                    chain_id,
+                    ..ConsensusParameters::DEFAULT
+                };
+                assert_eq!(
+                    predicate_owner(&predicate, chain_id),
+                    *Input::predicate_owner(&predicate, &cparams)
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use fuel_tx::ConsensusParameters;
+
+use crate::manifest::hex_string;
+use crate::{selector, MessageReceiverVersion};
+
+// Constants of the message-to-contract predicate and script shared with other codebases
+struct Constants {
+    predicate_root: String,
+    script_hash: String,
+    function_selector: String,
+    predicate_bytecode: String,
+    script_bytecode: String,
+}
+
+impl Constants {
+    fn new(cparams: &ConsensusParameters) -> Self {
+        let fn_sel =
+            selector::selector_from_signature(MessageReceiverVersion::V1.function_signature());
+        Constants {
+            predicate_root: hex_string(&crate::predicate_root(cparams)),
+            script_hash: hex_string(&crate::script_hash()),
+            function_selector: hex_string(&fn_sel),
+            predicate_bytecode: hex::encode(crate::predicate_bytecode()),
+            script_bytecode: hex::encode(crate::script_bytecode()),
+        }
+    }
+}
+
+// Generates a Solidity library with the constants of the message-to-contract predicate and script
+pub fn solidity_library(cparams: &ConsensusParameters) -> String {
+    let constants = Constants::new(cparams);
+    format!(
+        r#"// SPDX-License-Identifier: Apache-2.0
+// Generated by fuel-contract-message-predicate. Do not edit.
+pragma solidity ^0.8.9;
+
+/// @notice Constants of the Fuel message-to-contract predicate and script
+library ContractMessagePredicate {{
+    /// @notice Root of the predicate that messages to Fuel contracts are sent to
+    bytes32 internal constant PREDICATE_ROOT = {};
+
+    /// @notice SHA-256 hash of the script the predicate requires
+    bytes32 internal constant SCRIPT_HASH = {};
+
+    /// @notice Selector of the function the script calls on the target contract
+    bytes4 internal constant FUNCTION_SELECTOR = {};
+
+    /// @notice Bytecode of the predicate
+    bytes internal constant PREDICATE_BYTECODE = hex"{}";
+
+    /// @notice Bytecode of the script
+    bytes internal constant SCRIPT_BYTECODE = hex"{}";
+}}
+"#,
+        constants.predicate_root,
+        constants.script_hash,
+        constants.function_selector,
+        constants.predicate_bytecode,
+        constants.script_bytecode,
+    )
+}
+
+// Generates a TypeScript module with the constants of the message-to-contract predicate and script
+pub fn typescript_module(cparams: &ConsensusParameters) -> String {
+    let constants = Constants::new(cparams);
+    format!(
+        r#"// Generated by fuel-contract-message-predicate. Do not edit.
+
+/** Root of the predicate that messages to Fuel contracts are sent to */
+export const PREDICATE_ROOT = '{}';
+
+/** SHA-256 hash of the script the predicate requires */
+export const SCRIPT_HASH = '{}';
+
+/** Selector of the function the script calls on the target contract */
+export const FUNCTION_SELECTOR = '{}';
+
+/** Bytecode of the predicate */
+export const PREDICATE_BYTECODE = '0x{}';
+
+/** Bytecode of the script */
+export const SCRIPT_BYTECODE = '0x{}';
+"#,
+        constants.predicate_root,
+        constants.script_hash,
+        constants.function_selector,
+        constants.predicate_bytecode,
+        constants.script_bytecode,
+    )
+}
+
+// Generates the expected values shared by the tests of the Python, wasm and C bindings, as
+// `KEY=value` lines with hex encoded values
+pub fn bindings_fixture() -> String {
+    let contract_id = [7u8; 32];
+    let payload = [1u8, 2, 3];
+    let chain_id_1 = ConsensusParameters {
+        chain_id: 1,
+        ..ConsensusParameters::DEFAULT
+    };
+    format!(
+        r#"# Generated by fuel-contract-message-predicate. Do not edit.
+# Expected values for the binding tests, with the default consensus parameters unless noted
+SCRIPT_HASH={}
+PREDICATE_ROOT={}
+PREDICATE_ROOT_CHAIN_ID_1={}
+MESSAGE_DATA_CONTRACT_ID={}
+MESSAGE_DATA_PAYLOAD={}
+MESSAGE_DATA={}
+"#,
+        hex::encode(crate::script_hash()),
+        hex::encode(crate::predicate_root(&ConsensusParameters::DEFAULT)),
+        hex::encode(crate::predicate_root(&chain_id_1)),
+        hex::encode(contract_id),
+        hex::encode(payload),
+        hex::encode(crate::message_data(&contract_id, &payload)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Ensure the generated Solidity library doesn't change
+    #[test]
+    fn snapshot_solidity_library() {
+        let source = solidity_library(&ConsensusParameters::default());
+        insta::assert_snapshot!(source);
+    }
+
+    // Ensure the generated TypeScript module doesn't change
+    #[test]
+    fn snapshot_typescript_module() {
+        let source = typescript_module(&ConsensusParameters::default());
+        insta::assert_snapshot!(source);
+    }
+
+    // Ensure the committed fixture of the binding tests is up to date; regenerate it by running
+    // this test with `UPDATE_FIXTURES=1` if this fails
+    #[test]
+    fn bindings_fixture_is_up_to_date() {
+        if std::env::var_os("UPDATE_FIXTURES").is_some() {
+            let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/bindings.txt");
+            std::fs::write(path, bindings_fixture()).unwrap();
+            return;
+        }
+        assert_eq!(
+            include_str!("../tests/fixtures/bindings.txt"),
+            bindings_fixture()
+        );
+    }
+}
@@ -1,16 +1,34 @@
 contract;
 
 use contract_message_receiver::MessageReceiver;
-use std::bytes::Bytes;
+use contract_message_receiver::message::{
+    message_amount,
+    message_id,
+    message_sender,
+    require_message_for_this_contract,
+    require_trusted_sender,
+};
+use contract_message_receiver::message_data::MessageDataReader;
+use contract_message_receiver::processed_messages::{is_processed, mark_processed};
 use std::constants::ZERO_B256;
-use std::inputs::{input_message_data, input_message_data_length};
+use std::storage::storage_vec::*;
+
+configurable {
+    TRUSTED_SENDER: b256 = 0xca400d3e7710eee293786830755278e6d2b9278b4177b8b1a896ebd5f55c10bc,
+}
 
 storage {
     counter: u64 = 0,
+    sender: Address = Address::from(ZERO_B256),
+    amount: u64 = 0,
     data1: ContractId = ContractId::from(ZERO_B256),
     data2: u64 = 0,
     data3: b256 = ZERO_B256,
     data4: Address = Address::from(ZERO_B256),
+    data5: u8 = 0,
+    data6: u16 = 0,
+    data7: StorageVec<u8> = StorageVec {},
+    processed: StorageMap<b256, bool> = StorageMap {},
 }
 
 // Define verification abi
@@ -18,6 +36,10 @@ abi VerifyMessageData {
     #[storage(read)]
     fn test_counter() -> u64;
     #[storage(read)]
+    fn test_sender() -> Address;
+    #[storage(read)]
+    fn test_amount() -> u64;
+    #[storage(read)]
     fn test_data1() -> ContractId;
     #[storage(read)]
     fn test_data2() -> u64;
@@ -25,15 +47,14 @@ abi VerifyMessageData {
     fn test_data3() -> b256;
     #[storage(read)]
     fn test_data4() -> Address;
-}
-
-// Converts a Bytes type to u64
-// TODO: remove once an [into(self) -> u64] is added for the Bytes type
-fn into_u64(b: Bytes) -> u64 {
-    asm(ptr: b.buf.ptr, r0) {
-        lw   r0 ptr i0;
-        r0: u64
-    }
+    #[storage(read)]
+    fn test_data5() -> u8;
+    #[storage(read)]
+    fn test_data6() -> u16;
+    #[storage(read)]
+    fn test_data7() -> Vec<u8>;
+    #[storage(read)]
+    fn test_processed(message_id: b256) -> bool;
 }
 
 // Implement the process_message function required to be a message receiver
@@ -41,26 +62,39 @@ impl MessageReceiver for Contract {
     #[storage(read, write)]
     #[payable]
     fn process_message(msg_idx: u8) {
+        require_message_for_this_contract(msg_idx);
+        require_trusted_sender(msg_idx, Address::from(TRUSTED_SENDER));
+        mark_processed(storage.processed, message_id(msg_idx));
+
         storage.counter.write(0); // Temporary fix for: https://github.com/FuelLabs/sway/issues/4634
         storage.counter.write(storage.counter.read() + 1);
+        storage.sender.write(message_sender(msg_idx));
+        storage.amount.write(message_amount(msg_idx));
 
         // Parse the message data
-        let data_length = input_message_data_length(msg_idx);
-        if (data_length >= 32u16) {
-            let contract_id: b256 = input_message_data(msg_idx, 0).into();
-            storage.data1.write(ContractId::from(contract_id));
+        let mut reader = MessageDataReader::new(msg_idx);
+        storage.data1.write(reader.read_contract_id());
+        if (reader.remaining() >= 8) {
+            storage.data2.write(reader.read_u64());
+        }
+        if (reader.remaining() >= 32) {
+            storage.data3.write(reader.read_b256());
         }
-        if (data_length >= 32u16 + 8u16) {
-            let num: u64 = into_u64(input_message_data(msg_idx, 32));
-            storage.data2.write(num);
+        if (reader.remaining() >= 32) {
+            storage.data4.write(reader.read_address());
         }
-        if (data_length >= 32u16 + 8u16 + 32u16) {
-            let big_num: b256 = input_message_data(msg_idx, 32 + 8).into();
-            storage.data3.write(big_num);
+        if (reader.remaining() >= 1) {
+            storage.data5.write(reader.read_u8());
         }
-        if (data_length >= 32u16 + 8u16 + 32u16 + 32u16) {
-            let address: b256 = input_message_data(msg_idx, 32 + 8 + 32).into();
-            storage.data4.write(Address::from(address));
+        if (reader.remaining() >= 2) {
+            storage.data6.write(reader.read_u16());
+        }
+        storage.data7.clear();
+        let tail = reader.read_remaining();
+        let mut i = 0;
+        while i < tail.len() {
+            storage.data7.push(tail.get(i).unwrap());
+            i += 1;
         }
     }
 }
@@ -72,6 +106,16 @@ impl VerifyMessageData for Contract {
         storage.counter.read()
     }
 
+    #[storage(read)]
+    fn test_sender() -> Address {
+        storage.sender.read()
+    }
+
+    #[storage(read)]
+    fn test_amount() -> u64 {
+        storage.amount.read()
+    }
+
     #[storage(read)]
     fn test_data1() -> ContractId {
         storage.data1.read()
@@ -91,4 +135,30 @@ impl VerifyMessageData for Contract {
     fn test_data4() -> Address {
         storage.data4.read()
     }
+
+    #[storage(read)]
+    fn test_data5() -> u8 {
+        storage.data5.read()
+    }
+
+    #[storage(read)]
+    fn test_data6() -> u16 {
+        storage.data6.read()
+    }
+
+    #[storage(read)]
+    fn test_data7() -> Vec<u8> {
+        let mut data = Vec::new();
+        let mut i = 0;
+        while i < storage.data7.len() {
+            data.push(storage.data7.get(i).unwrap().read());
+            i += 1;
+        }
+        data
+    }
+
+    #[storage(read)]
+    fn test_processed(message_id: b256) -> bool {
+        is_processed(storage.processed, message_id)
+    }
 }
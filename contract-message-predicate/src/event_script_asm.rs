@@ -0,0 +1,143 @@
+use alloc::{vec, vec::Vec};
+use fuel_asm::{op, GTFArgs, Instruction, RegId};
+
+use crate::script_asm::PROCESS_MESSAGE_FUNCTION_SIGNATURE;
+use crate::selector;
+
+const BYTES_PER_INSTR: u16 = 4;
+
+// Byte length of the relay event logged by the event script [message id, contract id]
+pub(crate) const RELAY_EVENT_LEN: u16 = 32 + 32;
+
+// Tag of the relay event, in register `ra` of the LOGD receipt
+pub const RELAY_EVENT_TAG: u64 = 0x2e1a7;
+
+// Gets the bytecode for the message-to-contract script that logs a relay event for indexers
+pub fn bytecode() -> Vec<u8> {
+    //calculate function selector
+    let fn_sel = selector::selector_from_signature(PROCESS_MESSAGE_FUNCTION_SIGNATURE);
+
+    //register names
+    const REG_MEMORY_START_PTR: u8 = 0x10;
+    const REG_ASSET_PTR: u8 = REG_MEMORY_START_PTR;
+    const REG_DATA_PTR: u8 = 0x11;
+    const REG_DATA_FN_SEL_PTR: u8 = 0x12;
+    const REG_CONTRACT_ADDR_PTR: u8 = 0x13;
+    const REG_FN_SELECTOR_PTR: u8 = 0x14;
+    const REG_MSG_AMOUNT: u8 = 0x15;
+    const REG_MSG_DATA_LEN: u8 = 0x16;
+    const REG_PREIMAGE_PTR: u8 = 0x17;
+    const REG_PREIMAGE_LEN: u8 = 0x18;
+    const REG_FIELD_PTR: u8 = 0x19;
+    const REG_DEST_PTR: u8 = 0x1a;
+    const REG_EVENT_PTR: u8 = 0x1b;
+    const REG_EVENT_TAG: u8 = 0x1c;
+    const REG_EVENT_LEN: u8 = 0x1d;
+
+    //referenced data start pointer
+    const REF_DATA_START_PTR: u16 = 33 * BYTES_PER_INSTR;
+
+    /* The following assembly code is intended to do the following:
+     *  - Compute the ID of the message in input[0] as the hash of its sender,
+     *   recipient, nonce, amount and data, the same way as fuel-tx
+     *  - Log a relay event with `LOGD` tagged with the relay event tag, holding the
+     *   forwarded amount in `rb` and the message ID followed by the target contract
+     *   ID as data
+     *  - Call the function `process_message` on the contract with ID that matches
+     *   the first 32 bytes in the message data field, while forwarding the exact
+     *   amount of base asset specified in the `InputMessage` `amount` field
+     *
+     * note: the event is logged before the call, since a reverting call reverts the
+     *  script; the call outcome is given by the script result receipt
+     * note: this code makes the assumption that all memory at VM initialization is set to 0
+     */
+    let mut script: Vec<u8> = vec![
+        //extend stack for contract call data and the relay event
+        op::move_(REG_MEMORY_START_PTR, RegId::SP), //REG_MEMORY_START_PTR = stack pointer
+        op::cfei(32 + 32 + 8 + 8 + RELAY_EVENT_LEN as u32), //extends current call frame stack by 32+32+8+8+32+32 bytes [base asset id, contract id, param1, param2, message id, contract id]
+        op::addi(REG_DATA_PTR, REG_MEMORY_START_PTR, 32), //REG_DATA_PTR = REG_MEMORY_START_PTR + 32bytes [memory start pointer + 32]
+        op::addi(REG_DATA_FN_SEL_PTR, REG_DATA_PTR, 32 + 4), //REG_DATA_FN_SEL_PTR = REG_DATA_PTR + 32bytes + 4bytes [call data start pointer + 32 + 4]
+        //read message data
+        op::gtf(
+            REG_MSG_AMOUNT,
+            RegId::ZERO,
+            GTFArgs::InputMessageAmount.into(),
+        ), //REG_MSG_AMOUNT = amount value of message from input[0]
+        op::gtf(
+            REG_CONTRACT_ADDR_PTR,
+            RegId::ZERO,
+            GTFArgs::InputMessageData.into(),
+        ), //REG_CONTRACT_ADDR_PTR = memory location of the message data from input[0]
+        op::gtf(
+            REG_MSG_DATA_LEN,
+            RegId::ZERO,
+            GTFArgs::InputMessageDataLength.into(),
+        ), //REG_MSG_DATA_LEN = the data length of input[0]
+        //extend stack for the message id preimage
+        op::move_(REG_PREIMAGE_PTR, RegId::SP), //REG_PREIMAGE_PTR = stack pointer
+        op::addi(REG_PREIMAGE_LEN, REG_MSG_DATA_LEN, 32 + 32 + 32 + 8), //REG_PREIMAGE_LEN = REG_MSG_DATA_LEN + 32+32+32+8 bytes [sender, recipient, nonce, amount, data]
+        op::cfe(REG_PREIMAGE_LEN), //extends current call frame stack by REG_PREIMAGE_LEN bytes
+        //build the message id preimage
+        op::gtf(
+            REG_FIELD_PTR,
+            RegId::ZERO,
+            GTFArgs::InputMessageSender.into(),
+        ), //REG_FIELD_PTR = memory location of the sender of input[0]
+        op::mcpi(REG_PREIMAGE_PTR, REG_FIELD_PTR, 32), //32 bytes at REG_PREIMAGE_PTR = the 32 bytes at REG_FIELD_PTR
+        op::gtf(
+            REG_FIELD_PTR,
+            RegId::ZERO,
+            GTFArgs::InputMessageRecipient.into(),
+        ), //REG_FIELD_PTR = memory location of the recipient of input[0]
+        op::addi(REG_DEST_PTR, REG_PREIMAGE_PTR, 32), //REG_DEST_PTR = REG_PREIMAGE_PTR + 32bytes [recipient]
+        op::mcpi(REG_DEST_PTR, REG_FIELD_PTR, 32), //32 bytes at REG_DEST_PTR = the 32 bytes at REG_FIELD_PTR
+        op::gtf(
+            REG_FIELD_PTR,
+            RegId::ZERO,
+            GTFArgs::InputMessageNonce.into(),
+        ), //REG_FIELD_PTR = memory location of the nonce of input[0]
+        op::addi(REG_DEST_PTR, REG_PREIMAGE_PTR, 32 + 32), //REG_DEST_PTR = REG_PREIMAGE_PTR + 64bytes [nonce]
+        op::mcpi(REG_DEST_PTR, REG_FIELD_PTR, 32), //32 bytes at REG_DEST_PTR = the 32 bytes at REG_FIELD_PTR
+        op::sw(REG_PREIMAGE_PTR, REG_MSG_AMOUNT, 12), //word 12 at REG_PREIMAGE_PTR = REG_MSG_AMOUNT [amount at 96 bytes]
+        op::addi(REG_DEST_PTR, REG_PREIMAGE_PTR, 32 + 32 + 32 + 8), //REG_DEST_PTR = REG_PREIMAGE_PTR + 104bytes [data]
+        op::mcp(REG_DEST_PTR, REG_CONTRACT_ADDR_PTR, REG_MSG_DATA_LEN), //REG_MSG_DATA_LEN bytes at REG_DEST_PTR = the message data of input[0]
+        //build the relay event
+        op::addi(REG_EVENT_PTR, REG_MEMORY_START_PTR, 32 + 32 + 8 + 8), //REG_EVENT_PTR = REG_MEMORY_START_PTR + 80bytes [relay event]
+        op::s256(REG_EVENT_PTR, REG_PREIMAGE_PTR, REG_PREIMAGE_LEN), //32 bytes at REG_EVENT_PTR = sha256 of the REG_PREIMAGE_LEN bytes at REG_PREIMAGE_PTR
+        op::addi(REG_DEST_PTR, REG_EVENT_PTR, 32), //REG_DEST_PTR = REG_EVENT_PTR + 32bytes [contract id]
+        op::mcpi(REG_DEST_PTR, REG_CONTRACT_ADDR_PTR, 32), //32 bytes at REG_DEST_PTR = the 32 bytes at REG_CONTRACT_ADDR_PTR
+        //log the relay event
+        op::movi(REG_EVENT_TAG, RELAY_EVENT_TAG as u32), //REG_EVENT_TAG = relay event tag
+        op::movi(REG_EVENT_LEN, RELAY_EVENT_LEN as u32), //REG_EVENT_LEN = 32 + 32 [message id, contract id]
+        op::logd(REG_EVENT_TAG, REG_MSG_AMOUNT, REG_EVENT_PTR, REG_EVENT_LEN), //log REG_EVENT_LEN bytes at REG_EVENT_PTR with REG_EVENT_TAG and REG_MSG_AMOUNT
+        //prep call parameters
+        op::addi(REG_FN_SELECTOR_PTR, RegId::IS, REF_DATA_START_PTR), //REG_FN_SELECTOR_PTR = function selector at end of program
+        op::mcpi(REG_DATA_PTR, REG_CONTRACT_ADDR_PTR, 32), //32 bytes at REG_DATA_PTR = the 32 bytes at REG_CONTRACT_ADDR_PTR
+        op::mcpi(REG_DATA_FN_SEL_PTR, REG_FN_SELECTOR_PTR, 4), //4 bytes at REG_DATA_FN_SEL_PTR = the 4 bytes at REG_FN_SELECTOR_PTR
+        //make contract call
+        op::call(REG_DATA_PTR, REG_MSG_AMOUNT, REG_ASSET_PTR, RegId::CGAS),
+        op::ret(RegId::ZERO),
+        //referenced data (function selector)
+        //00000000
+    ]
+    .into_iter()
+    .flat_map(Instruction::to_bytes)
+    .collect();
+
+    //add referenced data (function selector)
+    script.extend_from_slice(&fn_sel);
+    script
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Ensure the event script bytecode doesn't change
+    #[test]
+    fn snapshot_event_script_bytecode() {
+        let bytecode = bytecode();
+        let serialized = hex::encode(&bytecode);
+        insta::assert_snapshot!(serialized);
+    }
+}
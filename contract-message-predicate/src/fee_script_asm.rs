@@ -0,0 +1,121 @@
+use alloc::{vec, vec::Vec};
+use fuel_asm::{op, GTFArgs, Instruction, RegId};
+
+use crate::script_asm::PROCESS_MESSAGE_FUNCTION_SIGNATURE;
+use crate::selector;
+
+const BYTES_PER_INSTR: u16 = 4;
+
+// Byte length of the data a fee message starts with [contract id, max relayer fee]
+pub(crate) const FEE_HEADER_LEN: u16 = 32 + 8;
+
+// Gets the bytecode for the message-to-contract script that pays a relayer fee
+pub fn bytecode() -> Vec<u8> {
+    //calculate function selector
+    let fn_sel = selector::selector_from_signature(PROCESS_MESSAGE_FUNCTION_SIGNATURE);
+
+    //register names
+    const REG_MEMORY_START_PTR: u8 = 0x10;
+    const REG_ASSET_PTR: u8 = REG_MEMORY_START_PTR;
+    const REG_DATA_PTR: u8 = 0x11;
+    const REG_DATA_FN_SEL_PTR: u8 = 0x12;
+    const REG_CONTRACT_ADDR_PTR: u8 = 0x13;
+    const REG_FN_SELECTOR_PTR: u8 = 0x14;
+    const REG_MSG_AMOUNT: u8 = 0x15;
+    const REG_LENGTH: u8 = 0x16;
+    const REG_MIN_LENGTH: u8 = 0x17;
+    const REG_RESULT: u8 = 0x18;
+    const REG_MAX_FEE: u8 = 0x19;
+    const REG_FEE_PTR: u8 = 0x1a;
+    const REG_FEE: u8 = 0x1b;
+
+    //instruction jump points
+    const JMP_FEE_FAILURE: u16 = 27;
+
+    //referenced data start pointer
+    const REF_DATA_START_PTR: u16 = 28 * BYTES_PER_INSTR;
+
+    /* The following assembly code is intended to do the following:
+     *  - Read the relayer fee from the first word of the script data and verify that it
+     *   is no more than the max relayer fee found at bytes 32..40 of the message data
+     *   and no more than the `InputMessage` `amount`
+     *  - Call the function `process_message` on the contract with ID that matches
+     *   the first 32 bytes in the message data field, while forwarding the amount of
+     *   base asset specified in the `InputMessage` `amount` field minus the relayer fee
+     *
+     * The relayer fee is left unspent and is paid out by the base asset change output.
+     *
+     * note: this code makes the assumption that all memory at VM initialization is set to 0
+     */
+    let mut script: Vec<u8> = vec![
+        //extend stack for contract call data
+        op::move_(REG_MEMORY_START_PTR, RegId::SP), //REG_MEMORY_START_PTR = stack pointer
+        op::cfei(32 + 32 + 8 + 8), //extends current call frame stack by 32+32+8+8 bytes [base asset id, contract id, param1, param2]
+        op::addi(REG_DATA_PTR, REG_MEMORY_START_PTR, 32), //REG_DATA_PTR = REG_MEMORY_START_PTR + 32bytes [memory start pointer + 32]
+        op::addi(REG_DATA_FN_SEL_PTR, REG_DATA_PTR, 32 + 4), //REG_DATA_FN_SEL_PTR = REG_DATA_PTR + 32bytes + 4bytes [call data start pointer + 32 + 4]
+        //read max relayer fee
+        op::gtf(
+            REG_MSG_AMOUNT,
+            RegId::ZERO,
+            GTFArgs::InputMessageAmount.into(),
+        ), //REG_MSG_AMOUNT = amount value of message from input[0]
+        op::gtf(
+            REG_CONTRACT_ADDR_PTR,
+            RegId::ZERO,
+            GTFArgs::InputMessageData.into(),
+        ), //REG_CONTRACT_ADDR_PTR = memory location of the message data from input[0]
+        op::gtf(
+            REG_LENGTH,
+            RegId::ZERO,
+            GTFArgs::InputMessageDataLength.into(),
+        ), //REG_LENGTH = the data length of input[0]
+        op::movi(REG_MIN_LENGTH, FEE_HEADER_LEN as u32), //REG_MIN_LENGTH = 32 + 8 [contract id, max relayer fee]
+        op::lt(REG_RESULT, REG_LENGTH, REG_MIN_LENGTH), //REG_RESULT = if REG_LENGTH is less than REG_MIN_LENGTH
+        op::jnei(REG_RESULT, RegId::ZERO, JMP_FEE_FAILURE), //jumps to FEE_FAILURE if REG_RESULT is not 0
+        op::lw(REG_MAX_FEE, REG_CONTRACT_ADDR_PTR, 4), //REG_MAX_FEE = word at REG_CONTRACT_ADDR_PTR + 32bytes
+        //read relayer fee
+        op::gtf(REG_LENGTH, RegId::ZERO, GTFArgs::ScriptDataLength.into()), //REG_LENGTH = script data length
+        op::movi(REG_MIN_LENGTH, 8), //REG_MIN_LENGTH = 8 [relayer fee]
+        op::lt(REG_RESULT, REG_LENGTH, REG_MIN_LENGTH), //REG_RESULT = if REG_LENGTH is less than REG_MIN_LENGTH
+        op::jnei(REG_RESULT, RegId::ZERO, JMP_FEE_FAILURE), //jumps to FEE_FAILURE if REG_RESULT is not 0
+        op::gtf(REG_FEE_PTR, RegId::ZERO, GTFArgs::ScriptData.into()), //REG_FEE_PTR = script data address
+        op::lw(REG_FEE, REG_FEE_PTR, 0), //REG_FEE = word at REG_FEE_PTR
+        //check relayer fee
+        op::lt(REG_RESULT, REG_MAX_FEE, REG_FEE), //REG_RESULT = if REG_MAX_FEE is less than REG_FEE
+        op::jnei(REG_RESULT, RegId::ZERO, JMP_FEE_FAILURE), //jumps to FEE_FAILURE if REG_RESULT is not 0
+        op::lt(REG_RESULT, REG_MSG_AMOUNT, REG_FEE), //REG_RESULT = if REG_MSG_AMOUNT is less than REG_FEE
+        op::jnei(REG_RESULT, RegId::ZERO, JMP_FEE_FAILURE), //jumps to FEE_FAILURE if REG_RESULT is not 0
+        op::sub(REG_MSG_AMOUNT, REG_MSG_AMOUNT, REG_FEE), //REG_MSG_AMOUNT = REG_MSG_AMOUNT - REG_FEE
+        //prep call parameters
+        op::addi(REG_FN_SELECTOR_PTR, RegId::IS, REF_DATA_START_PTR), //REG_FN_SELECTOR_PTR = function selector at end of program
+        op::mcpi(REG_DATA_PTR, REG_CONTRACT_ADDR_PTR, 32), //32 bytes at REG_DATA_PTR = the 32 bytes at REG_CONTRACT_ADDR_PTR
+        op::mcpi(REG_DATA_FN_SEL_PTR, REG_FN_SELECTOR_PTR, 4), //4 bytes at REG_DATA_FN_SEL_PTR = the 4 bytes at REG_FN_SELECTOR_PTR
+        //make contract call
+        op::call(REG_DATA_PTR, REG_MSG_AMOUNT, REG_ASSET_PTR, RegId::CGAS),
+        op::ret(RegId::ZERO),
+        //FEE_FAILURE:
+        op::rvrt(RegId::ZERO),
+        //referenced data (function selector)
+        //00000000
+    ]
+    .into_iter()
+    .flat_map(Instruction::to_bytes)
+    .collect();
+
+    //add referenced data (function selector)
+    script.extend_from_slice(&fn_sel);
+    script
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Ensure the fee script bytecode doesn't change
+    #[test]
+    fn snapshot_fee_script_bytecode() {
+        let bytecode = bytecode();
+        let serialized = hex::encode(&bytecode);
+        insta::assert_snapshot!(serialized);
+    }
+}
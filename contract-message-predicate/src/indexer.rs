@@ -0,0 +1,463 @@
+use std::{fmt, io, path::Path};
+
+use fuel_core_client::client::FuelClient;
+use fuel_tx::{
+    field::{Inputs, Script as _},
+    ConsensusParameters, PanicReason, Receipt, Transaction, UniqueIdentifier,
+};
+
+use crate::relay_event::RelayOutcome;
+
+// Key of the height of the next block to index
+const NEXT_HEIGHT_KEY: &[u8] = b"next_height";
+
+// Key prefixes of the relay records, by message ID and by target contract ID
+const MESSAGE_PREFIX: u8 = b'm';
+const CONTRACT_PREFIX: u8 = b'c';
+
+// Byte length of an encoded relay record
+const RECORD_LEN: usize = 8 + 32 + 32 + 32 + 32 + 32 + 8 + 1 + 8;
+
+// Error of the relay indexer
+#[derive(Debug)]
+pub enum IndexerError {
+    // The database failed to read or write
+    Database(sled::Error),
+    // The node failed to answer a query
+    Client(io::Error),
+    // The node has no block at the given height
+    MissingBlock(u64),
+    // The node has no transaction with the given ID
+    MissingTransaction(String),
+    // A value read from the database doesn't have the expected length
+    InvalidLength { expected: usize, actual: usize },
+}
+
+impl fmt::Display for IndexerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IndexerError::Database(error) => write!(f, "database error: {error}"),
+            IndexerError::Client(error) => write!(f, "node client error: {error}"),
+            IndexerError::MissingBlock(height) => write!(f, "missing block at height {height}"),
+            IndexerError::MissingTransaction(tx_id) => write!(f, "missing transaction {tx_id}"),
+            IndexerError::InvalidLength { expected, actual } => {
+                write!(f, "invalid value length {actual}, expected {expected}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for IndexerError {}
+
+impl From<sled::Error> for IndexerError {
+    fn from(error: sled::Error) -> Self {
+        IndexerError::Database(error)
+    }
+}
+
+impl From<io::Error> for IndexerError {
+    fn from(error: io::Error) -> Self {
+        IndexerError::Client(error)
+    }
+}
+
+// Relay of a message to a contract by a transaction running the message-to-contract script
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelayRecord {
+    pub block_height: u64,
+    pub tx_id: [u8; 32],
+    pub message_id: [u8; 32],
+    pub sender: [u8; 32],
+    pub nonce: [u8; 32],
+    pub contract_id: [u8; 32],
+    pub amount: u64,
+    pub outcome: RelayOutcome,
+}
+
+impl RelayRecord {
+    // Extracts the relay made by a transaction, if it runs the message-to-contract script. The
+    // relayed message is input[0], whose data starts with the target contract ID. Other message
+    // inputs only pay for the transaction, so they are not recorded.
+    pub fn from_transaction(
+        block_height: u64,
+        tx: &Transaction,
+        receipts: &[Receipt],
+        cparams: &ConsensusParameters,
+    ) -> Option<Self> {
+        let script = match tx {
+            Transaction::Script(script) => script,
+            _ => return None,
+        };
+        if *script.script() != crate::script_bytecode() {
+            return None;
+        }
+        let message = script.inputs().first()?;
+        let contract_id: [u8; 32] = match message.input_data() {
+            Some(data) if data.len() >= 32 => data[..32].try_into().unwrap(),
+            _ => return None,
+        };
+        let outcome = RelayOutcome::from_receipts(receipts)?;
+        Some(RelayRecord {
+            block_height,
+            tx_id: tx.id(cparams).into(),
+            message_id: message.message_id()?.into(),
+            sender: (*message.sender()?).into(),
+            nonce: (*message.nonce()?).into(),
+            contract_id,
+            amount: message.amount()?,
+            outcome,
+        })
+    }
+
+    fn to_bytes(self) -> Vec<u8> {
+        let (outcome, code) = match self.outcome {
+            RelayOutcome::Success => (0, 0),
+            RelayOutcome::Revert(code) => (1, code),
+            RelayOutcome::Panic(reason) => (2, reason as u64),
+        };
+        let mut bytes = Vec::with_capacity(RECORD_LEN);
+        bytes.extend_from_slice(&self.block_height.to_be_bytes());
+        bytes.extend_from_slice(&self.tx_id);
+        bytes.extend_from_slice(&self.message_id);
+        bytes.extend_from_slice(&self.sender);
+        bytes.extend_from_slice(&self.nonce);
+        bytes.extend_from_slice(&self.contract_id);
+        bytes.extend_from_slice(&self.amount.to_be_bytes());
+        bytes.push(outcome);
+        bytes.extend_from_slice(&code.to_be_bytes());
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, IndexerError> {
+        if bytes.len() != RECORD_LEN {
+            return Err(IndexerError::InvalidLength {
+                expected: RECORD_LEN,
+                actual: bytes.len(),
+            });
+        }
+        let word = |at: usize| u64::from_be_bytes(bytes[at..at + 8].try_into().unwrap());
+        let hash = |at: usize| -> [u8; 32] { bytes[at..at + 32].try_into().unwrap() };
+        let code = word(RECORD_LEN - 8);
+        Ok(RelayRecord {
+            block_height: word(0),
+            tx_id: hash(8),
+            message_id: hash(40),
+            sender: hash(72),
+            nonce: hash(104),
+            contract_id: hash(136),
+            amount: word(168),
+            outcome: match bytes[176] {
+                0 => RelayOutcome::Success,
+                1 => RelayOutcome::Revert(code),
+                _ => RelayOutcome::Panic(PanicReason::from(code as u8)),
+            },
+        })
+    }
+
+    // Key of the record under the given prefix and ID, ordered by block height
+    fn key(&self, prefix: u8, id: &[u8; 32]) -> Vec<u8> {
+        let mut key = vec![prefix];
+        key.extend_from_slice(id);
+        key.extend_from_slice(&self.block_height.to_be_bytes());
+        key.extend_from_slice(&self.tx_id);
+        key.extend_from_slice(&self.message_id);
+        key
+    }
+}
+
+// Index of the relays of messages to contracts, stored in an embedded sled database. Blocks are
+// fed in by the caller or read from a Fuel node with `index_from_node`, and an indexer reopened
+// on the same path resumes from `next_height`. Indexing a block again doesn't duplicate its
+// relays.
+pub struct RelayIndexer {
+    db: sled::Db,
+    cparams: ConsensusParameters,
+}
+
+impl RelayIndexer {
+    // Opens the index stored at the given path, for the chain with the given consensus parameters
+    pub fn open<P: AsRef<Path>>(
+        path: P,
+        cparams: ConsensusParameters,
+    ) -> Result<Self, IndexerError> {
+        let db = sled::open(path)?;
+        Ok(RelayIndexer { db, cparams })
+    }
+
+    // Opens an index that is deleted when dropped
+    pub fn temporary(cparams: ConsensusParameters) -> Result<Self, IndexerError> {
+        let db = sled::Config::new().temporary(true).open()?;
+        Ok(RelayIndexer { db, cparams })
+    }
+
+    // Gets the height of the next block to index
+    pub fn next_height(&self) -> Result<u64, IndexerError> {
+        match self.db.get(NEXT_HEIGHT_KEY)? {
+            Some(height) => {
+                let height = height[..]
+                    .try_into()
+                    .map_err(|_| IndexerError::InvalidLength {
+                        expected: 8,
+                        actual: height.len(),
+                    })?;
+                Ok(u64::from_be_bytes(height))
+            }
+            None => Ok(0),
+        }
+    }
+
+    // Indexes the transactions of the block at the given height, each with its receipts, and
+    // returns the relays found in the block
+    pub fn index_block(
+        &self,
+        height: u64,
+        transactions: &[(Transaction, Vec<Receipt>)],
+    ) -> Result<Vec<RelayRecord>, IndexerError> {
+        let relays: Vec<RelayRecord> = transactions
+            .iter()
+            .flat_map(|(tx, receipts)| {
+                RelayRecord::from_transaction(height, tx, receipts, &self.cparams)
+            })
+            .collect();
+
+        let mut batch = sled::Batch::default();
+        for relay in &relays {
+            batch.insert(
+                relay.key(MESSAGE_PREFIX, &relay.message_id),
+                relay.to_bytes(),
+            );
+            batch.insert(
+                relay.key(CONTRACT_PREFIX, &relay.contract_id),
+                relay.to_bytes(),
+            );
+        }
+        let next_height = self.next_height()?.max(height + 1);
+        batch.insert(NEXT_HEIGHT_KEY, &next_height.to_be_bytes()[..]);
+        self.db.apply_batch(batch)?;
+        self.db.flush()?;
+        Ok(relays)
+    }
+
+    // Indexes the blocks of the node behind the given client, from `next_height` up to its latest
+    // block, and returns the relays found in them
+    pub async fn index_from_node(
+        &self,
+        client: &FuelClient,
+    ) -> Result<Vec<RelayRecord>, IndexerError> {
+        let latest_height = latest_block_height(client).await?;
+        let mut relays = vec![];
+        for height in self.next_height()?..=latest_height {
+            let transactions = block_transactions(client, height).await?;
+            relays.extend(self.index_block(height, &transactions)?);
+        }
+        Ok(relays)
+    }
+
+    // Gets the relays of the message with the given ID, ordered by block height
+    pub fn relays_of_message(
+        &self,
+        message_id: &[u8; 32],
+    ) -> Result<Vec<RelayRecord>, IndexerError> {
+        self.scan(MESSAGE_PREFIX, message_id)
+    }
+
+    // Gets the relays to the contract with the given ID, ordered by block height
+    pub fn relays_to_contract(
+        &self,
+        contract_id: &[u8; 32],
+    ) -> Result<Vec<RelayRecord>, IndexerError> {
+        self.scan(CONTRACT_PREFIX, contract_id)
+    }
+
+    // Checks if the message with the given ID has been delivered, that is relayed successfully
+    pub fn is_delivered(&self, message_id: &[u8; 32]) -> Result<bool, IndexerError> {
+        let relays = self.relays_of_message(message_id)?;
+        Ok(relays
+            .iter()
+            .any(|relay| relay.outcome == RelayOutcome::Success))
+    }
+
+    fn scan(&self, prefix: u8, id: &[u8; 32]) -> Result<Vec<RelayRecord>, IndexerError> {
+        let mut key = vec![prefix];
+        key.extend_from_slice(id);
+        self.db
+            .scan_prefix(key)
+            .map(|entry| RelayRecord::from_bytes(&entry?.1))
+            .collect()
+    }
+}
+
+// Gets the height of the latest block of the node behind the given client
+pub async fn latest_block_height(client: &FuelClient) -> Result<u64, IndexerError> {
+    let chain_info = client.chain_info().await?;
+    Ok(u32::from(chain_info.latest_block.header.height).into())
+}
+
+// Gets the transactions of the block at the given height from the node behind the given client,
+// each with its receipts
+pub async fn block_transactions(
+    client: &FuelClient,
+    height: u64,
+) -> Result<Vec<(Transaction, Vec<Receipt>)>, IndexerError> {
+    let block = client
+        .block_by_height(height)
+        .await?
+        .ok_or(IndexerError::MissingBlock(height))?;
+
+    let mut transactions = vec![];
+    for tx_id in block.transactions {
+        let tx_id = tx_id.id.to_string();
+        let tx = client
+            .transaction(&tx_id)
+            .await?
+            .ok_or_else(|| IndexerError::MissingTransaction(tx_id.clone()))?
+            .transaction;
+        // Note: mint transactions have no receipts
+        let receipts = client.receipts(&tx_id).await?.unwrap_or_default();
+        transactions.push((tx, receipts));
+    }
+    Ok(transactions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_tx::{Input, ScriptExecutionResult};
+
+    fn relay_tx(script: Vec<u8>, contract_id: [u8; 32], nonce: u8) -> Transaction {
+        let message = Input::message_data_predicate(
+            [1u8; 32].into(),
+            crate::predicate_root(&ConsensusParameters::DEFAULT).into(),
+            100,
+            [nonce; 32].into(),
+            crate::message_data(&contract_id, &[]),
+            crate::predicate_bytecode(),
+            vec![],
+        );
+        let fee_message = Input::message_coin_signed(
+            [1u8; 32].into(),
+            [3u8; 32].into(),
+            50,
+            [nonce; 32].into(),
+            0,
+        );
+        let script = Transaction::script(
+            0,
+            0,
+            Default::default(),
+            script,
+            vec![],
+            vec![message, fee_message],
+            vec![],
+            vec![],
+        );
+        script.into()
+    }
+
+    fn receipts(result: ScriptExecutionResult) -> Vec<Receipt> {
+        let mut receipts = vec![];
+        if result == ScriptExecutionResult::Revert {
+            receipts.push(Receipt::revert([2u8; 32].into(), 42, 0, 0));
+        }
+        receipts.push(Receipt::script_result(result, 0));
+        receipts
+    }
+
+    #[test]
+    fn indexes_relays_of_message_to_contract_script() {
+        let indexer = RelayIndexer::temporary(ConsensusParameters::DEFAULT).unwrap();
+        assert_eq!(indexer.next_height().unwrap(), 0);
+
+        let relay = relay_tx(crate::script_bytecode(), [2u8; 32], 0);
+        let other = relay_tx(crate::fee_script_bytecode(), [2u8; 32], 1);
+        let relays = indexer
+            .index_block(
+                3,
+                &[
+                    (relay.clone(), receipts(ScriptExecutionResult::Revert)),
+                    (other, receipts(ScriptExecutionResult::Success)),
+                ],
+            )
+            .unwrap();
+        assert_eq!(relays.len(), 1);
+        assert_eq!(relays[0].block_height, 3);
+        assert_eq!(relays[0].contract_id, [2u8; 32]);
+        assert_eq!(relays[0].sender, [1u8; 32]);
+        assert_eq!(relays[0].amount, 100);
+        assert_eq!(relays[0].outcome, RelayOutcome::Revert(42));
+        assert_eq!(indexer.next_height().unwrap(), 4);
+
+        let message_id = relays[0].message_id;
+        assert!(!indexer.is_delivered(&message_id).unwrap());
+        indexer
+            .index_block(5, &[(relay, receipts(ScriptExecutionResult::Success))])
+            .unwrap();
+        assert!(indexer.is_delivered(&message_id).unwrap());
+
+        let relays = indexer.relays_of_message(&message_id).unwrap();
+        assert_eq!(
+            relays
+                .iter()
+                .map(|relay| (relay.block_height, relay.outcome))
+                .collect::<Vec<_>>(),
+            vec![(3, RelayOutcome::Revert(42)), (5, RelayOutcome::Success)]
+        );
+        assert_eq!(indexer.relays_to_contract(&[2u8; 32]).unwrap().len(), 2);
+        assert_eq!(indexer.relays_to_contract(&[3u8; 32]).unwrap(), vec![]);
+        assert_eq!(indexer.next_height().unwrap(), 6);
+    }
+
+    #[test]
+    fn does_not_record_messages_paying_for_relays() {
+        let indexer = RelayIndexer::temporary(ConsensusParameters::DEFAULT).unwrap();
+        let relay = relay_tx(crate::script_bytecode(), [2u8; 32], 0);
+        let fee_message_id: [u8; 32] = relay.as_script().unwrap().inputs()[1]
+            .message_id()
+            .unwrap()
+            .into();
+
+        let relays = indexer
+            .index_block(3, &[(relay, receipts(ScriptExecutionResult::Success))])
+            .unwrap();
+        assert_eq!(relays.len(), 1);
+        assert_ne!(relays[0].message_id, fee_message_id);
+        assert!(indexer.is_delivered(&relays[0].message_id).unwrap());
+
+        // The message without data in the same transaction is not delivered
+        assert_eq!(indexer.relays_of_message(&fee_message_id).unwrap(), vec![]);
+        assert!(!indexer.is_delivered(&fee_message_id).unwrap());
+    }
+
+    #[test]
+    fn encodes_records() {
+        for outcome in [
+            RelayOutcome::Success,
+            RelayOutcome::Revert(u64::MAX),
+            RelayOutcome::Panic(PanicReason::ContractNotInInputs),
+        ] {
+            let record = RelayRecord {
+                block_height: 7,
+                tx_id: [1u8; 32],
+                message_id: [2u8; 32],
+                sender: [3u8; 32],
+                nonce: [4u8; 32],
+                contract_id: [5u8; 32],
+                amount: 100,
+                outcome,
+            };
+            assert_eq!(RelayRecord::from_bytes(&record.to_bytes()).unwrap(), record);
+        }
+    }
+
+    #[test]
+    fn rejects_records_of_invalid_length() {
+        let error = RelayRecord::from_bytes(&[0u8; RECORD_LEN - 1]).unwrap_err();
+        assert!(matches!(
+            error,
+            IndexerError::InvalidLength {
+                expected: RECORD_LEN,
+                actual
+            } if actual == RECORD_LEN - 1
+        ));
+    }
+}
@@ -0,0 +1,84 @@
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "std")]
+use fuel_tx::ConsensusParameters;
+
+use crate::predicate_asm;
+
+// Type of an input that may follow the message and target contract inputs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputKind {
+    Coin = 0,
+    Contract = 1,
+    Message = 2,
+}
+
+// Transaction layout enforced by the strict message-to-contract predicate. The transaction must
+// have the message as the first input, the contract input of the target contract as the second
+// input and the contract output for it as the first output. Any further inputs must be of the
+// given kinds (messages must still have no data)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxLayoutPolicy {
+    pub max_inputs: u8,
+    pub extra_inputs: Vec<InputKind>,
+}
+
+impl Default for TxLayoutPolicy {
+    // Allows a single extra input, which must be a coin (for paying gas)
+    fn default() -> Self {
+        TxLayoutPolicy {
+            max_inputs: 3,
+            extra_inputs: vec![InputKind::Coin],
+        }
+    }
+}
+
+impl TxLayoutPolicy {
+    // Gets the bytecode of the strict predicate for this policy
+    pub fn predicate_bytecode(&self) -> Vec<u8> {
+        predicate_asm::strict_bytecode_for_script(
+            crate::script_hash(),
+            self.max_inputs,
+            self.extra_input_types_mask(),
+        )
+    }
+
+    // Gets the root of the strict predicate for this policy
+    #[cfg(feature = "std")]
+    pub fn predicate_root(&self, cparams: &ConsensusParameters) -> [u8; 32] {
+        crate::root(&self.predicate_bytecode(), cparams)
+    }
+
+    fn extra_input_types_mask(&self) -> u8 {
+        self.extra_inputs
+            .iter()
+            .fold(0, |mask, kind| mask | (1 << *kind as u8))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policies_have_distinct_predicates() {
+        let default_policy = TxLayoutPolicy::default();
+        let message_policy = TxLayoutPolicy {
+            extra_inputs: vec![InputKind::Coin, InputKind::Message],
+            ..Default::default()
+        };
+        let larger_policy = TxLayoutPolicy {
+            max_inputs: 4,
+            ..Default::default()
+        };
+        assert_eq!(default_policy.extra_input_types_mask(), 0b001);
+        assert_eq!(message_policy.extra_input_types_mask(), 0b101);
+        assert_ne!(
+            default_policy.predicate_bytecode(),
+            message_policy.predicate_bytecode()
+        );
+        assert_ne!(
+            default_policy.predicate_bytecode(),
+            larger_policy.predicate_bytecode()
+        );
+    }
+}
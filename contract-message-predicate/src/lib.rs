@@ -1,24 +1,416 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod allowlist;
+#[cfg(feature = "std")]
+pub mod attestation;
+mod code_root;
+#[cfg(feature = "std")]
+pub mod codegen;
+mod event_script_asm;
+mod fee_script_asm;
+#[cfg(feature = "indexer")]
+pub mod indexer;
+pub mod layout_policy;
+#[cfg(feature = "std")]
+pub mod manifest;
+mod pinned_script_asm;
 mod predicate_asm;
+mod receiver_version;
+mod refund_script_asm;
+#[cfg(feature = "std")]
+pub mod relay_event;
 mod script_asm;
+mod script_v2_asm;
+pub mod selector;
+mod self_funded_script_asm;
+pub mod sender_allowlist;
+mod sweep_script_asm;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
-use fuel_tx::{ConsensusParameters, Input};
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use fuel_tx::{
+    field::{Inputs, Outputs, ReceiptsRoot, Witnesses},
+    ConsensusParameters, Input, Output, Script,
+};
+#[cfg(feature = "std")]
+use fuel_types::bytes::SerializableVec;
 use sha2::{Digest, Sha256};
 
 // Make the script and predicate bytecode public
+#[cfg(feature = "std")]
+pub use code_root::contract_code_root;
+pub use code_root::{code_root, predicate_owner};
+pub use event_script_asm::{bytecode as event_script_bytecode, RELAY_EVENT_TAG};
+pub use fee_script_asm::bytecode as fee_script_bytecode;
+pub use pinned_script_asm::{bytecode as pinned_script_bytecode, CODE_ROOT_MISMATCH_REVERT_CODE};
 pub use predicate_asm::bytecode as predicate_bytecode;
+pub use receiver_version::MessageReceiverVersion;
+pub use refund_script_asm::bytecode as refund_script_bytecode;
 pub use script_asm::bytecode as script_bytecode;
+pub use self_funded_script_asm::{
+    bytecode as self_funded_script_bytecode, FEE_CAP_EXCEEDED_REVERT_CODE,
+};
+pub use sweep_script_asm::bytecode as sweep_script_bytecode;
 
 // Gets the hash of the message-to-contract script
 pub fn script_hash() -> [u8; 32] {
-    let script = script_asm::bytecode();
-    let mut script_hasher = Sha256::new();
-    script_hasher.update(script);
-    script_hasher.finalize().into()
+    MessageReceiverVersion::V1.script_hash()
 }
 
 // Gets the root of the message-to-contract predicate
+#[cfg(feature = "std")]
 pub fn predicate_root(cparams: &ConsensusParameters) -> [u8; 32] {
-    let predicate = predicate_asm::bytecode();
-    let root = Input::predicate_owner(predicate, &cparams);
-    root.into()
+    MessageReceiverVersion::V1.predicate_root(cparams)
+}
+
+// Builds the data of a message-to-contract message: the target contract ID followed by the
+// receiver payload
+pub fn message_data(contract_id: &[u8; 32], payload: &[u8]) -> Vec<u8> {
+    let mut data = Vec::with_capacity(32 + payload.len());
+    data.extend_from_slice(contract_id);
+    data.extend_from_slice(payload);
+    data
+}
+
+// Gets the hash of the message refund script
+pub fn refund_script_hash() -> [u8; 32] {
+    sha256(&refund_script_bytecode())
+}
+
+// Gets the bytecode of the refundable message-to-contract predicate, which can also be
+// spent by the refund script once the refund height in the message data is reached
+pub fn refundable_predicate_bytecode() -> Vec<u8> {
+    predicate_asm::refundable_bytecode_for_scripts(script_hash(), refund_script_hash())
+}
+
+// Gets the root of the refundable message-to-contract predicate
+#[cfg(feature = "std")]
+pub fn refundable_predicate_root(cparams: &ConsensusParameters) -> [u8; 32] {
+    root(&refundable_predicate_bytecode(), cparams)
+}
+
+// Builds the data of a refundable message: the target contract ID, the refund address and
+// the block height from which the message can be refunded, followed by the receiver payload
+pub fn refundable_message_data(
+    contract_id: &[u8; 32],
+    refund_address: &[u8; 32],
+    refund_height: u64,
+    payload: &[u8],
+) -> Vec<u8> {
+    let mut data = Vec::with_capacity(refund_script_asm::REFUND_HEADER_LEN + payload.len());
+    data.extend_from_slice(contract_id);
+    data.extend_from_slice(refund_address);
+    data.extend_from_slice(&refund_height.to_be_bytes());
+    data.extend_from_slice(payload);
+    data
+}
+
+// Builds the script data of a refund that proves the given relay of a refundable message
+// reverted: the chain ID followed by the relay transaction as it is signed, whose hash is the
+// transaction ID
+#[cfg(feature = "std")]
+pub fn refund_proof_script_data(relay_tx: &Script, cparams: &ConsensusParameters) -> Vec<u8> {
+    let mut tx = relay_tx.clone();
+    *tx.receipts_root_mut() = Default::default();
+    tx.witnesses_mut().clear();
+    tx.inputs_mut().iter_mut().for_each(|input| match input {
+        Input::CoinSigned(coin) => coin.prepare_sign(),
+        Input::CoinPredicate(coin) => coin.prepare_sign(),
+        Input::Contract(contract) => contract.prepare_sign(),
+        Input::MessageCoinSigned(message) => message.prepare_sign(),
+        Input::MessageCoinPredicate(message) => message.prepare_sign(),
+        Input::MessageDataSigned(message) => message.prepare_sign(),
+        Input::MessageDataPredicate(message) => message.prepare_sign(),
+    });
+    tx.outputs_mut().iter_mut().for_each(|output| match output {
+        Output::Contract {
+            balance_root,
+            state_root,
+            ..
+        } => {
+            *balance_root = Default::default();
+            *state_root = Default::default();
+        }
+        Output::Change { amount, .. } => *amount = 0,
+        Output::Variable {
+            to,
+            amount,
+            asset_id,
+        } => {
+            *to = Default::default();
+            *amount = 0;
+            *asset_id = Default::default();
+        }
+        _ => {}
+    });
+
+    let mut data = cparams.chain_id.to_be_bytes().to_vec();
+    data.extend_from_slice(&tx.to_bytes());
+    data
+}
+
+// Gets the bytecode of the time-locked message-to-contract predicate, which can only be spent
+// by transactions with a maturity of at least the minimum block height in the message data
+pub fn time_locked_predicate_bytecode() -> Vec<u8> {
+    predicate_asm::time_locked_bytecode_for_script(script_hash())
+}
+
+// Gets the root of the time-locked message-to-contract predicate
+#[cfg(feature = "std")]
+pub fn time_locked_predicate_root(cparams: &ConsensusParameters) -> [u8; 32] {
+    root(&time_locked_predicate_bytecode(), cparams)
+}
+
+// Builds the data of a time-locked message: the target contract ID and the minimum block
+// height at which the message can be relayed, followed by the receiver payload
+pub fn time_locked_message_data(
+    contract_id: &[u8; 32],
+    min_height: u64,
+    payload: &[u8],
+) -> Vec<u8> {
+    let mut data = Vec::with_capacity(32 + 8 + payload.len());
+    data.extend_from_slice(contract_id);
+    data.extend_from_slice(&min_height.to_be_bytes());
+    data.extend_from_slice(payload);
+    data
+}
+
+// Gets the bytecode of the sealed outputs message-to-contract predicate, which only allows
+// outputs through which the message amount cannot reach anyone but the target contract
+pub fn sealed_outputs_predicate_bytecode() -> Vec<u8> {
+    predicate_asm::sealed_outputs_bytecode_for_script(script_hash())
+}
+
+// Gets the root of the sealed outputs message-to-contract predicate
+#[cfg(feature = "std")]
+pub fn sealed_outputs_predicate_root(cparams: &ConsensusParameters) -> [u8; 32] {
+    root(&sealed_outputs_predicate_bytecode(), cparams)
+}
+
+// Gets the bytecode of the relayer-authorized message-to-contract predicate, which can only be
+// spent by transactions signed by the relayer in the message data
+pub fn relayer_predicate_bytecode() -> Vec<u8> {
+    predicate_asm::relayer_bytecode_for_script(script_hash())
+}
+
+// Gets the root of the relayer-authorized message-to-contract predicate
+#[cfg(feature = "std")]
+pub fn relayer_predicate_root(cparams: &ConsensusParameters) -> [u8; 32] {
+    root(&relayer_predicate_bytecode(), cparams)
+}
+
+// Gets the address of the relayer with the given uncompressed public key: the hash of the key
+pub fn relayer_address(public_key: &[u8; 64]) -> [u8; 32] {
+    sha256(public_key)
+}
+
+// Builds the data of a relayer-authorized message: the target contract ID and the relayer address,
+// followed by the receiver payload
+pub fn relayer_message_data(contract_id: &[u8; 32], relayer: &[u8; 32], payload: &[u8]) -> Vec<u8> {
+    let mut data = Vec::with_capacity(32 + 32 + payload.len());
+    data.extend_from_slice(contract_id);
+    data.extend_from_slice(relayer);
+    data.extend_from_slice(payload);
+    data
+}
+
+// Builds the predicate data of a relayer-authorized message: the index of the transaction witness
+// holding the relayer signature over the transaction ID, as a word. The signature can't be in the
+// predicate data itself, since predicate data is part of the transaction ID.
+pub fn relayer_predicate_data(witness_index: u8) -> Vec<u8> {
+    u64::from(witness_index).to_be_bytes().to_vec()
+}
+
+// Gets the bytecode of the relay window message-to-contract predicate, which can only be spent by
+// transactions signed by the relayer in the message data until the deadline in the message data,
+// and by anyone with a transaction maturity of at least the deadline
+pub fn relay_window_predicate_bytecode() -> Vec<u8> {
+    predicate_asm::relay_window_bytecode_for_script(script_hash())
+}
+
+// Gets the root of the relay window message-to-contract predicate
+#[cfg(feature = "std")]
+pub fn relay_window_predicate_root(cparams: &ConsensusParameters) -> [u8; 32] {
+    root(&relay_window_predicate_bytecode(), cparams)
+}
+
+// Builds the data of a relay window message: the target contract ID, the relayer address and the
+// block height at which anyone can relay the message, followed by the receiver payload. Relays by
+// the relayer use the predicate data of `relayer_predicate_data`.
+pub fn relay_window_message_data(
+    contract_id: &[u8; 32],
+    relayer: &[u8; 32],
+    deadline: u64,
+    payload: &[u8],
+) -> Vec<u8> {
+    let mut data = Vec::with_capacity(32 + 32 + 8 + payload.len());
+    data.extend_from_slice(contract_id);
+    data.extend_from_slice(relayer);
+    data.extend_from_slice(&deadline.to_be_bytes());
+    data.extend_from_slice(payload);
+    data
+}
+
+// Gets the hash of the message-to-contract script that pays a relayer fee
+pub fn fee_script_hash() -> [u8; 32] {
+    sha256(&fee_script_bytecode())
+}
+
+// Gets the bytecode of the relayer fee message-to-contract predicate, which requires the
+// script that caps the relayer fee at the max fee in the message data
+pub fn fee_predicate_bytecode() -> Vec<u8> {
+    predicate_asm::bytecode_for_script(fee_script_hash())
+}
+
+// Gets the root of the relayer fee message-to-contract predicate
+#[cfg(feature = "std")]
+pub fn fee_predicate_root(cparams: &ConsensusParameters) -> [u8; 32] {
+    root(&fee_predicate_bytecode(), cparams)
+}
+
+// Builds the data of a relayer fee message: the target contract ID and the max relayer fee,
+// followed by the receiver payload
+pub fn fee_message_data(contract_id: &[u8; 32], max_fee: u64, payload: &[u8]) -> Vec<u8> {
+    let mut data = Vec::with_capacity(fee_script_asm::FEE_HEADER_LEN as usize + payload.len());
+    data.extend_from_slice(contract_id);
+    data.extend_from_slice(&max_fee.to_be_bytes());
+    data.extend_from_slice(payload);
+    data
+}
+
+// Gets the hash of the message-to-contract script that checks the code root of the target
+pub fn pinned_script_hash() -> [u8; 32] {
+    sha256(&pinned_script_bytecode())
+}
+
+// Gets the bytecode of the code root pinned message-to-contract predicate, which requires the
+// script that only calls the target contract if its code root is the one in the message data
+pub fn pinned_predicate_bytecode() -> Vec<u8> {
+    predicate_asm::bytecode_for_script(pinned_script_hash())
+}
+
+// Gets the root of the code root pinned message-to-contract predicate
+#[cfg(feature = "std")]
+pub fn pinned_predicate_root(cparams: &ConsensusParameters) -> [u8; 32] {
+    root(&pinned_predicate_bytecode(), cparams)
+}
+
+// Builds the data of a code root pinned message: the target contract ID and the expected code
+// root of the target contract (see `code_root`), followed by the receiver payload
+pub fn pinned_message_data(
+    contract_id: &[u8; 32],
+    code_root: &[u8; 32],
+    payload: &[u8],
+) -> Vec<u8> {
+    let mut data =
+        Vec::with_capacity(pinned_script_asm::PINNED_HEADER_LEN as usize + payload.len());
+    data.extend_from_slice(contract_id);
+    data.extend_from_slice(code_root);
+    data.extend_from_slice(payload);
+    data
+}
+
+// Gets the hash of the message-to-contract script that logs a relay event for indexers
+pub fn event_script_hash() -> [u8; 32] {
+    sha256(&event_script_bytecode())
+}
+
+// Gets the bytecode of the relay event message-to-contract predicate, which requires the script
+// that logs a relay event before calling the target contract. Message data is built with
+// `message_data`, and the event is decoded with `relay_event::RelayEvent::from_receipts`.
+pub fn event_predicate_bytecode() -> Vec<u8> {
+    predicate_asm::bytecode_for_script(event_script_hash())
+}
+
+// Gets the root of the relay event message-to-contract predicate
+#[cfg(feature = "std")]
+pub fn event_predicate_root(cparams: &ConsensusParameters) -> [u8; 32] {
+    root(&event_predicate_bytecode(), cparams)
+}
+
+// Gets the hash of the message-to-contract script that pays the transaction fee from the message
+pub fn self_funded_script_hash() -> [u8; 32] {
+    sha256(&self_funded_script_bytecode())
+}
+
+// Gets the bytecode of the self-funded message-to-contract predicate, which requires the script
+// that pays the transaction fee from the message amount, up to the fee cap in the message data
+pub fn self_funded_predicate_bytecode() -> Vec<u8> {
+    predicate_asm::bytecode_for_script(self_funded_script_hash())
+}
+
+// Gets the root of the self-funded message-to-contract predicate
+#[cfg(feature = "std")]
+pub fn self_funded_predicate_root(cparams: &ConsensusParameters) -> [u8; 32] {
+    root(&self_funded_predicate_bytecode(), cparams)
+}
+
+// Builds the data of a self-funded message: the target contract ID and the fee cap, followed by
+// the receiver payload
+pub fn self_funded_message_data(contract_id: &[u8; 32], fee_cap: u64, payload: &[u8]) -> Vec<u8> {
+    let mut data =
+        Vec::with_capacity(self_funded_script_asm::SELF_FUNDED_HEADER_LEN as usize + payload.len());
+    data.extend_from_slice(contract_id);
+    data.extend_from_slice(&fee_cap.to_be_bytes());
+    data.extend_from_slice(payload);
+    data
+}
+
+// Gets the hash of the script that sweeps coins owned by the recoverable predicate
+pub fn sweep_script_hash() -> [u8; 32] {
+    sha256(&sweep_script_bytecode())
+}
+
+// Gets the bytecode of the recoverable message-to-contract predicate with the given recovery
+// address. Messages with data can only be spent by the message-to-contract script, while coins and
+// messages without data can also be swept to the recovery address with the sweep script.
+pub fn recoverable_predicate_bytecode(recovery_address: &[u8; 32]) -> Vec<u8> {
+    predicate_asm::recoverable_bytecode_for_scripts(
+        script_hash(),
+        sweep_script_hash(),
+        *recovery_address,
+    )
+}
+
+// Gets the root of the recoverable message-to-contract predicate with the given recovery address
+#[cfg(feature = "std")]
+pub fn recoverable_predicate_root(
+    recovery_address: &[u8; 32],
+    cparams: &ConsensusParameters,
+) -> [u8; 32] {
+    root(&recoverable_predicate_bytecode(recovery_address), cparams)
+}
+
+// Gets the SHA-256 hash of the given bytes
+pub(crate) fn sha256(bytes: &[u8]) -> [u8; 32] {
+    Sha256::digest(bytes).into()
+}
+
+// Gets the root of the given predicate bytecode
+#[cfg(feature = "std")]
+pub(crate) fn root(predicate: &[u8], cparams: &ConsensusParameters) -> [u8; 32] {
+    Input::predicate_owner(predicate, cparams).into()
+}
+
+// Gets the ID of a message, as computed by fuel-tx and the receiver library
+#[cfg(feature = "std")]
+pub fn message_id(
+    sender: &[u8; 32],
+    recipient: &[u8; 32],
+    nonce: &[u8; 32],
+    amount: u64,
+    data: &[u8],
+) -> [u8; 32] {
+    let id = Input::compute_message_id(
+        &(*sender).into(),
+        &(*recipient).into(),
+        &(*nonce).into(),
+        amount,
+        data,
+    );
+    id.into()
 }
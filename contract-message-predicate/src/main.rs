@@ -1,30 +1,127 @@
-use fuel_tx::{Address, Bytes32, ConsensusParameters};
-use std::fs;
+use fuel_contract_message_predicate::{
+    attestation::Attestation,
+    codegen,
+    manifest::{self, Manifest, Network},
+    selector, MessageReceiverVersion,
+};
+use fuel_tx::ConsensusParameters;
 use std::path::Path;
+use std::{env, fs, process};
 
 const OUTPUT_DIR: &str = "./out";
-const SCRIPT_BUILD_PATH: &str = "./out/contract_message_script.bin";
-const PREDICATE_BUILD_PATH: &str = "./out/contract_message_predicate.bin";
+const MANIFEST_BUILD_PATH: &str = "./out/manifest.json";
+const SOLIDITY_BUILD_PATH: &str = "./out/ContractMessagePredicate.sol";
+const TYPESCRIPT_BUILD_PATH: &str = "./out/contractMessagePredicate.ts";
 
 fn main() {
-    //get predicate and script bytecode
-    let script = fuel_contract_message_predicate::script_bytecode();
-    let predicate = fuel_contract_message_predicate::predicate_bytecode();
-
-    //output to console and build files
-    let script_hash = fuel_contract_message_predicate::script_hash();
-    let predicate_root =
-        fuel_contract_message_predicate::predicate_root(&ConsensusParameters::default());
-    println!("Script bytecode size is {} bytes.", script.len());
-    println!("Script hash: 0x{}", Bytes32::from(script_hash));
-    println!("Predicate bytecode size is {} bytes.", predicate.len());
-    println!("Predicate root: 0x{}", Address::from(predicate_root));
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.as_slice() {
+        //print the function selector of a script binary (`cargo run -- selector <script.bin>`)
+        [command, script_path] if command == "selector" => {
+            print_script_selector(script_path);
+            return;
+        }
+        //print the manifest of all artifacts (`cargo run -- manifest > manifest.json`)
+        [command] if command == "manifest" => {
+            println!("{}", Manifest::build(&Network::configured()).to_json());
+            return;
+        }
+        //print the attestation of the bytecode (`cargo run -- attest`)
+        [command] if command == "attest" => {
+            println!("{}", Attestation::build(&Network::configured()).to_json());
+            return;
+        }
+        //check an attestation against rebuilt bytecode (`cargo run -- check-attestation <attestation.json>`)
+        [command, attestation_path] if command == "check-attestation" => {
+            check_attestation(attestation_path);
+            return;
+        }
+        _ => {}
+    }
+
     fs::create_dir_all(OUTPUT_DIR)
         .unwrap_or_else(|_| panic!("Failed to create output directory [{OUTPUT_DIR}]."));
-    fs::write(Path::new(SCRIPT_BUILD_PATH), script).unwrap_or_else(|_| {
-        panic!("Failed to wite to script binary file output [{SCRIPT_BUILD_PATH}].")
+
+    //output the binary of every script and predicate, with its hash or roots from the manifest
+    let manifest = Manifest::build(&Network::configured());
+    for (name, script) in manifest::script_artifacts() {
+        let artifact = &manifest.scripts[name];
+        println!("Script {name} bytecode size is {} bytes.", artifact.length);
+        println!("Script {name} hash: {}", artifact.sha256);
+        write_binary(name, &script);
+    }
+    for (name, predicate) in manifest::predicate_artifacts() {
+        let artifact = &manifest.predicates[name];
+        println!(
+            "Predicate {name} bytecode size is {} bytes.",
+            artifact.length
+        );
+        for (network, root) in &artifact.roots {
+            println!("Predicate {name} root on {network}: {root}");
+        }
+        write_binary(name, &predicate);
+    }
+
+    //output manifest of all artifacts
+    fs::write(Path::new(MANIFEST_BUILD_PATH), manifest.to_json()).unwrap_or_else(|_| {
+        panic!("Failed to write to manifest file output [{MANIFEST_BUILD_PATH}].")
     });
-    fs::write(Path::new(PREDICATE_BUILD_PATH), predicate).unwrap_or_else(|_| {
-        panic!("Failed to wite to predicate binary file output [{PREDICATE_BUILD_PATH}].")
+    println!("Manifest written to {MANIFEST_BUILD_PATH}.");
+
+    //output generated Solidity and TypeScript constants
+    let solidity = codegen::solidity_library(&ConsensusParameters::default());
+    let typescript = codegen::typescript_module(&ConsensusParameters::default());
+    fs::write(Path::new(SOLIDITY_BUILD_PATH), solidity).unwrap_or_else(|_| {
+        panic!("Failed to write to Solidity file output [{SOLIDITY_BUILD_PATH}].")
     });
+    fs::write(Path::new(TYPESCRIPT_BUILD_PATH), typescript).unwrap_or_else(|_| {
+        panic!("Failed to write to TypeScript file output [{TYPESCRIPT_BUILD_PATH}].")
+    });
+    println!("Solidity constants written to {SOLIDITY_BUILD_PATH}.");
+    println!("TypeScript constants written to {TYPESCRIPT_BUILD_PATH}.");
+}
+
+fn write_binary(name: &str, bytecode: &[u8]) {
+    let path = format!("{OUTPUT_DIR}/{name}.bin");
+    fs::write(Path::new(&path), bytecode)
+        .unwrap_or_else(|_| panic!("Failed to write to binary file output [{path}]."));
+}
+
+fn print_script_selector(script_path: &str) {
+    let script = fs::read(Path::new(script_path))
+        .unwrap_or_else(|_| panic!("Failed to read script binary file [{script_path}]."));
+    match selector::script_selector(&script) {
+        Some(fn_sel) => {
+            println!("Function selector: 0x{}", hex_string(&fn_sel));
+            match MessageReceiverVersion::ALL
+                .into_iter()
+                .find(|v| selector::selector_from_signature(v.function_signature()) == fn_sel)
+            {
+                Some(version) => println!("Function signature: {}", version.function_signature()),
+                None => println!("Function signature: unknown"),
+            }
+        }
+        None => println!("No function selector found in script [{script_path}]."),
+    }
+}
+
+fn check_attestation(attestation_path: &str) {
+    let json = fs::read_to_string(Path::new(attestation_path))
+        .unwrap_or_else(|_| panic!("Failed to read attestation file [{attestation_path}]."));
+    let attestation = Attestation::from_json(&json)
+        .unwrap_or_else(|_| panic!("Failed to parse attestation file [{attestation_path}]."));
+    match attestation.check() {
+        Ok(()) => println!("Attestation matches the rebuilt bytecode."),
+        Err(mismatches) => {
+            println!("Attestation does not match the rebuilt bytecode:");
+            for field in mismatches {
+                println!("  {field}");
+            }
+            process::exit(1);
+        }
+    }
+}
+
+fn hex_string(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
 }
@@ -0,0 +1,300 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::{fs, io};
+
+use fuel_tx::ConsensusParameters;
+use serde::{Deserialize, Serialize};
+
+use crate::{selector, MessageReceiverVersion};
+
+// Networks that predicate roots are computed for, as configured in `networks.json`
+const NETWORKS_JSON: &str = include_str!("../networks.json");
+
+// Machine-readable description of the released script and predicate artifacts, written to
+// `manifest.json` by the build. Artifacts are keyed by the name of their binary file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Manifest {
+    pub version: String,
+    pub networks: Vec<Network>,
+    pub scripts: BTreeMap<String, ScriptArtifact>,
+    pub predicates: BTreeMap<String, PredicateArtifact>,
+}
+
+// Network that predicate roots are computed for
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Network {
+    pub name: String,
+    pub chain_id: u64,
+}
+
+// Script artifact; byte strings are `0x` prefixed hex
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScriptArtifact {
+    pub bytecode: String,
+    pub length: usize,
+    pub sha256: String,
+    pub function_selector: Option<String>,
+}
+
+// Predicate artifact with its root on each network, keyed by network name
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PredicateArtifact {
+    pub bytecode: String,
+    pub length: usize,
+    pub roots: BTreeMap<String, String>,
+}
+
+impl Network {
+    // Gets the networks the build computes predicate roots for, as configured in `networks.json`
+    pub fn configured() -> Vec<Network> {
+        serde_json::from_str(NETWORKS_JSON).expect("Failed to parse networks.json")
+    }
+
+    // Gets the consensus parameters of this network relevant to predicate roots
+    pub fn consensus_parameters(&self) -> ConsensusParameters {
+        ConsensusParameters {
+            chain_id: self.chain_id,
+            ..ConsensusParameters::DEFAULT
+        }
+    }
+}
+
+impl Manifest {
+    // Builds the manifest of the compiled-in artifacts for the given networks
+    pub fn build(networks: &[Network]) -> Self {
+        Manifest {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            networks: networks.to_vec(),
+            scripts: script_artifacts()
+                .into_iter()
+                .map(|(name, script)| (name.to_string(), ScriptArtifact::new(&script)))
+                .collect(),
+            predicates: predicate_artifacts()
+                .into_iter()
+                .map(|(name, predicate)| {
+                    (
+                        name.to_string(),
+                        PredicateArtifact::new(&predicate, networks),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    // Parses a manifest from JSON
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    // Serializes the manifest to pretty-printed JSON
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Failed to serialize manifest")
+    }
+
+    // Loads a manifest from a JSON file
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let json = fs::read_to_string(path)?;
+        Ok(Self::from_json(&json)?)
+    }
+
+    // Checks the manifest against the compiled-in artifacts for its networks,
+    // returning the names of the entries that differ
+    pub fn check(&self) -> Result<(), Vec<String>> {
+        let expected = Self::build(&self.networks);
+        let mut mismatches = vec![];
+        if self.version != expected.version {
+            mismatches.push("version".to_string());
+        }
+        mismatches.extend(diff_keys("scripts", &self.scripts, &expected.scripts));
+        mismatches.extend(diff_keys(
+            "predicates",
+            &self.predicates,
+            &expected.predicates,
+        ));
+
+        match mismatches.is_empty() {
+            true => Ok(()),
+            false => Err(mismatches),
+        }
+    }
+}
+
+impl ScriptArtifact {
+    fn new(script: &[u8]) -> Self {
+        let sha256 = crate::sha256(script);
+        ScriptArtifact {
+            bytecode: hex_string(script),
+            length: script.len(),
+            sha256: hex_string(&sha256),
+            function_selector: selector::script_selector(script).map(|s| hex_string(&s)),
+        }
+    }
+}
+
+impl PredicateArtifact {
+    fn new(predicate: &[u8], networks: &[Network]) -> Self {
+        PredicateArtifact {
+            bytecode: hex_string(predicate),
+            length: predicate.len(),
+            roots: predicate_roots(predicate, networks),
+        }
+    }
+}
+
+// Gets the names and bytecode of the released scripts
+pub fn script_artifacts() -> Vec<(&'static str, Vec<u8>)> {
+    vec![
+        ("contract_message_script", crate::script_bytecode()),
+        (
+            "contract_message_script_v2",
+            MessageReceiverVersion::V2.script_bytecode(),
+        ),
+        (
+            "contract_message_refund_script",
+            crate::refund_script_bytecode(),
+        ),
+        ("contract_message_fee_script", crate::fee_script_bytecode()),
+        (
+            "contract_message_pinned_script",
+            crate::pinned_script_bytecode(),
+        ),
+        (
+            "contract_message_event_script",
+            crate::event_script_bytecode(),
+        ),
+        (
+            "contract_message_sweep_script",
+            crate::sweep_script_bytecode(),
+        ),
+        (
+            "contract_message_self_funded_script",
+            crate::self_funded_script_bytecode(),
+        ),
+    ]
+}
+
+// Gets the names and bytecode of the released predicates
+pub fn predicate_artifacts() -> Vec<(&'static str, Vec<u8>)> {
+    vec![
+        ("contract_message_predicate", crate::predicate_bytecode()),
+        (
+            "contract_message_predicate_v2",
+            MessageReceiverVersion::V2.predicate_bytecode(),
+        ),
+        (
+            "contract_message_refundable_predicate",
+            crate::refundable_predicate_bytecode(),
+        ),
+        (
+            "contract_message_time_locked_predicate",
+            crate::time_locked_predicate_bytecode(),
+        ),
+        (
+            "contract_message_fee_predicate",
+            crate::fee_predicate_bytecode(),
+        ),
+        (
+            "contract_message_sealed_outputs_predicate",
+            crate::sealed_outputs_predicate_bytecode(),
+        ),
+        (
+            "contract_message_relayer_predicate",
+            crate::relayer_predicate_bytecode(),
+        ),
+        (
+            "contract_message_relay_window_predicate",
+            crate::relay_window_predicate_bytecode(),
+        ),
+        (
+            "contract_message_pinned_predicate",
+            crate::pinned_predicate_bytecode(),
+        ),
+        (
+            "contract_message_event_predicate",
+            crate::event_predicate_bytecode(),
+        ),
+        (
+            "contract_message_self_funded_predicate",
+            crate::self_funded_predicate_bytecode(),
+        ),
+    ]
+}
+
+// Gets the roots of a predicate on the given networks, keyed by network name
+pub(crate) fn predicate_roots(predicate: &[u8], networks: &[Network]) -> BTreeMap<String, String> {
+    networks
+        .iter()
+        .map(|network| {
+            let root = crate::root(predicate, &network.consensus_parameters());
+            (network.name.clone(), hex_string(&root))
+        })
+        .collect()
+}
+
+pub(crate) fn diff_keys<T: PartialEq>(
+    section: &str,
+    actual: &BTreeMap<String, T>,
+    expected: &BTreeMap<String, T>,
+) -> Vec<String> {
+    let mut names: Vec<&String> = actual.keys().chain(expected.keys()).collect();
+    names.sort();
+    names.dedup();
+    names
+        .into_iter()
+        .filter(|name| actual.get(*name) != expected.get(*name))
+        .map(|name| format!("{section}.{name}"))
+        .collect()
+}
+
+pub(crate) fn hex_string(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manifest_round_trips_and_checks() {
+        let manifest = Manifest::build(&Network::configured());
+        let parsed = Manifest::from_json(&manifest.to_json()).unwrap();
+        assert_eq!(parsed, manifest);
+        assert_eq!(parsed.check(), Ok(()));
+
+        let script = &parsed.scripts["contract_message_script"];
+        assert_eq!(script.sha256, hex_string(&crate::script_hash()));
+        assert_eq!(
+            parsed.predicates["contract_message_predicate"].roots["local"],
+            hex_string(&crate::predicate_root(&ConsensusParameters::default()))
+        );
+    }
+
+    // Ensure the committed manifest matches the compiled-in artifacts and configured networks;
+    // regenerate it with `cargo run -- manifest > manifest.json` if this fails
+    #[test]
+    fn committed_manifest_is_up_to_date() {
+        let manifest = Manifest::from_json(include_str!("../manifest.json")).unwrap();
+        assert_eq!(manifest.networks, Network::configured());
+        assert_eq!(manifest.check(), Ok(()));
+    }
+
+    #[test]
+    fn check_reports_stale_entries() {
+        let mut manifest = Manifest::build(&Network::configured());
+        manifest.version = "0.0.0".to_string();
+        manifest
+            .scripts
+            .get_mut("contract_message_script")
+            .unwrap()
+            .bytecode = "0x".to_string();
+        manifest.predicates.remove("contract_message_fee_predicate");
+        assert_eq!(
+            manifest.check(),
+            Err(vec![
+                "version".to_string(),
+                "scripts.contract_message_script".to_string(),
+                "predicates.contract_message_fee_predicate".to_string(),
+            ])
+        );
+    }
+}
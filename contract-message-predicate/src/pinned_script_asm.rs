@@ -0,0 +1,125 @@
+use alloc::{vec, vec::Vec};
+use fuel_asm::{op, GTFArgs, Instruction, RegId};
+
+use crate::script_asm::PROCESS_MESSAGE_FUNCTION_SIGNATURE;
+use crate::selector;
+
+const BYTES_PER_INSTR: u16 = 4;
+
+// Byte length of the data a pinned message starts with [contract id, expected code root]
+pub(crate) const PINNED_HEADER_LEN: u16 = 32 + 32;
+
+// Revert code of the pinned script when the target contract code root is not the expected one
+pub const CODE_ROOT_MISMATCH_REVERT_CODE: u64 = 0xc0de;
+
+// Gets the bytecode for the message-to-contract script that checks the code root of the target
+pub fn bytecode() -> Vec<u8> {
+    //calculate function selector
+    let fn_sel = selector::selector_from_signature(PROCESS_MESSAGE_FUNCTION_SIGNATURE);
+
+    //register names
+    const REG_MEMORY_START_PTR: u8 = 0x10;
+    const REG_ASSET_PTR: u8 = REG_MEMORY_START_PTR;
+    const REG_DATA_PTR: u8 = 0x11;
+    const REG_DATA_FN_SEL_PTR: u8 = 0x12;
+    const REG_CONTRACT_ADDR_PTR: u8 = 0x13;
+    const REG_FN_SELECTOR_PTR: u8 = 0x14;
+    const REG_MSG_AMOUNT: u8 = 0x15;
+    const REG_LENGTH: u8 = 0x16;
+    const REG_MIN_LENGTH: u8 = 0x17;
+    const REG_RESULT: u8 = 0x18;
+    const REG_CODE_ROOT_PTR: u8 = 0x19;
+    const REG_EXPECTED_CODE_ROOT_PTR: u8 = 0x1a;
+    const REG_VAL_32: u8 = 0x1b;
+    const REG_REVERT_CODE: u8 = 0x1c;
+
+    //instruction jump points
+    const JMP_DATA_FAILURE: u16 = 21;
+    const JMP_CODE_ROOT_FAILURE: u16 = 22;
+
+    //referenced data start pointer
+    const REF_DATA_START_PTR: u16 = 24 * BYTES_PER_INSTR;
+
+    /* The following assembly code is intended to do the following:
+     *  - Verify that the code root of the contract with ID that matches the first
+     *   32 bytes in the message data field equals the expected code root found at
+     *   bytes 32..64 of the message data, and otherwise revert with the code root
+     *   mismatch revert code
+     *  - Call the function `process_message` on that contract, while forwarding the
+     *   exact amount of base asset specified in the `InputMessage` `amount` field
+     *
+     * note: this code makes the assumption that all memory at VM initialization is set to 0
+     */
+    let mut script: Vec<u8> = vec![
+        //extend stack for contract call data and the code root
+        op::move_(REG_MEMORY_START_PTR, RegId::SP), //REG_MEMORY_START_PTR = stack pointer
+        op::cfei(32 + 32 + 8 + 8 + 32), //extends current call frame stack by 32+32+8+8+32 bytes [base asset id, contract id, param1, param2, code root]
+        op::addi(REG_DATA_PTR, REG_MEMORY_START_PTR, 32), //REG_DATA_PTR = REG_MEMORY_START_PTR + 32bytes [memory start pointer + 32]
+        op::addi(REG_DATA_FN_SEL_PTR, REG_DATA_PTR, 32 + 4), //REG_DATA_FN_SEL_PTR = REG_DATA_PTR + 32bytes + 4bytes [call data start pointer + 32 + 4]
+        //read message data
+        op::gtf(
+            REG_MSG_AMOUNT,
+            RegId::ZERO,
+            GTFArgs::InputMessageAmount.into(),
+        ), //REG_MSG_AMOUNT = amount value of message from input[0]
+        op::gtf(
+            REG_CONTRACT_ADDR_PTR,
+            RegId::ZERO,
+            GTFArgs::InputMessageData.into(),
+        ), //REG_CONTRACT_ADDR_PTR = memory location of the message data from input[0]
+        op::gtf(
+            REG_LENGTH,
+            RegId::ZERO,
+            GTFArgs::InputMessageDataLength.into(),
+        ), //REG_LENGTH = the data length of input[0]
+        op::movi(REG_MIN_LENGTH, PINNED_HEADER_LEN as u32), //REG_MIN_LENGTH = 32 + 32 [contract id, expected code root]
+        op::lt(REG_RESULT, REG_LENGTH, REG_MIN_LENGTH), //REG_RESULT = if REG_LENGTH is less than REG_MIN_LENGTH
+        op::jnei(REG_RESULT, RegId::ZERO, JMP_DATA_FAILURE), //jumps to DATA_FAILURE if REG_RESULT is not 0
+        //compare the code root of the target contract with the expected code root
+        op::addi(REG_CODE_ROOT_PTR, REG_MEMORY_START_PTR, 32 + 32 + 8 + 8), //REG_CODE_ROOT_PTR = REG_MEMORY_START_PTR + 80bytes [code root]
+        op::croo(REG_CODE_ROOT_PTR, REG_CONTRACT_ADDR_PTR), //32 bytes at REG_CODE_ROOT_PTR = code root of the contract with ID at REG_CONTRACT_ADDR_PTR
+        op::addi(REG_EXPECTED_CODE_ROOT_PTR, REG_CONTRACT_ADDR_PTR, 32), //REG_EXPECTED_CODE_ROOT_PTR = REG_CONTRACT_ADDR_PTR + 32bytes [expected code root]
+        op::movi(REG_VAL_32, 32),                                        //REG_VAL_32 = 32
+        op::meq(
+            REG_RESULT,
+            REG_CODE_ROOT_PTR,
+            REG_EXPECTED_CODE_ROOT_PTR,
+            REG_VAL_32,
+        ), //REG_RESULT = if the 32bytes at REG_CODE_ROOT_PTR equals the 32bytes at REG_EXPECTED_CODE_ROOT_PTR
+        op::jnei(REG_RESULT, RegId::ONE, JMP_CODE_ROOT_FAILURE), //jumps to CODE_ROOT_FAILURE if REG_RESULT is not 1
+        //prep call parameters
+        op::addi(REG_FN_SELECTOR_PTR, RegId::IS, REF_DATA_START_PTR), //REG_FN_SELECTOR_PTR = function selector at end of program
+        op::mcpi(REG_DATA_PTR, REG_CONTRACT_ADDR_PTR, 32), //32 bytes at REG_DATA_PTR = the 32 bytes at REG_CONTRACT_ADDR_PTR
+        op::mcpi(REG_DATA_FN_SEL_PTR, REG_FN_SELECTOR_PTR, 4), //4 bytes at REG_DATA_FN_SEL_PTR = the 4 bytes at REG_FN_SELECTOR_PTR
+        //make contract call
+        op::call(REG_DATA_PTR, REG_MSG_AMOUNT, REG_ASSET_PTR, RegId::CGAS),
+        op::ret(RegId::ZERO),
+        //DATA_FAILURE:
+        op::rvrt(RegId::ZERO),
+        //CODE_ROOT_FAILURE:
+        op::movi(REG_REVERT_CODE, CODE_ROOT_MISMATCH_REVERT_CODE as u32), //REG_REVERT_CODE = code root mismatch revert code
+        op::rvrt(REG_REVERT_CODE),
+        //referenced data (function selector)
+        //00000000
+    ]
+    .into_iter()
+    .flat_map(Instruction::to_bytes)
+    .collect();
+
+    //add referenced data (function selector)
+    script.extend_from_slice(&fn_sel);
+    script
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Ensure the pinned script bytecode doesn't change
+    #[test]
+    fn snapshot_pinned_script_bytecode() {
+        let bytecode = bytecode();
+        let serialized = hex::encode(&bytecode);
+        insta::assert_snapshot!(serialized);
+    }
+}
@@ -1,22 +1,37 @@
-use fuel_asm::{op, GTFArgs, RegId};
+use alloc::{vec, vec::Vec};
+use fuel_asm::{op, GMArgs, GTFArgs, Instruction, RegId};
 
 const INPUT_MESSAGE_TYPE: u32 = 2;
+const OUTPUT_CHANGE_TYPE: u32 = 2;
+const OUTPUT_VARIABLE_TYPE: u32 = 3;
+const TIME_LOCK_HEADER_LEN: u32 = 32 + 8;
+const RELAYER_HEADER_LEN: u32 = 32 + 32;
+const RELAY_WINDOW_HEADER_LEN: u32 = 32 + 32 + 8;
 const BYTES_PER_INSTR: u16 = 4;
 
+// GTF argument of the contract ID of an input contract in fuel-vm 0.31, which fuel-asm 0.34
+// numbers differently (`GTFArgs::InputContractId` is 0x114 there)
+const GTF_INPUT_CONTRACT_ID: u16 = 0x113;
+
+//register names shared by all predicates
+const REG_HASH_PTR: u8 = 0x10;
+const REG_SCRIPT_PTR: u8 = 0x11;
+const REG_SCRIPT_LEN: u8 = 0x12;
+const REG_EXPECTED_HASH_PTR: u8 = 0x13;
+const REG_RESULT: u8 = 0x14;
+const REG_VAL_32: u8 = 0x16;
+const REG_INPUT_INDEX: u8 = 0x17;
+const REG_INPUT_TYPE: u8 = 0x18;
+const REG_INPUT_MSG_DATA_LEN: u8 = 0x19;
+const REG_EXPECTED_INPUT_TYPE: u8 = 0x1a;
+
 // Gets the bytecode for the message-to-contract predicate
 pub fn bytecode() -> Vec<u8> {
-    //register names
-    const REG_HASH_PTR: u8 = 0x10;
-    const REG_SCRIPT_PTR: u8 = 0x11;
-    const REG_SCRIPT_LEN: u8 = 0x12;
-    const REG_EXPECTED_HASH_PTR: u8 = 0x13;
-    const REG_RESULT: u8 = 0x14;
-    const REG_VAL_32: u8 = 0x16;
-    const REG_INPUT_INDEX: u8 = 0x17;
-    const REG_INPUT_TYPE: u8 = 0x18;
-    const REG_INPUT_MSG_DATA_LEN: u8 = 0x19;
-    const REG_EXPECTED_INPUT_TYPE: u8 = 0x1a;
+    bytecode_for_script(crate::script_hash())
+}
 
+// Gets the bytecode for a message-to-contract predicate that requires the script with the given hash
+pub fn bytecode_for_script(script_hash: [u8; 32]) -> Vec<u8> {
     //instruction jump points
     const JMP_LOOP_START: u16 = 11;
     const JMP_SKIP_DATA_CHECK: u16 = 16;
@@ -33,52 +48,1101 @@ pub fn bytecode() -> Vec<u8> {
      *
      * If these conditions are met, then the predicate evaluates as true.
      */
-    let mut predicate: Vec<u8> = vec![
-        //extend stack for storing script hash
-        op::move_(REG_HASH_PTR, RegId::SP), //REG_HASH_PTR = stack pointer
-        op::cfei(32),                       //extends current call frame stack by 32 bytes
+    let mut predicate: Vec<u8> = [
+        vec![
+            //extend stack for storing script hash
+            op::move_(REG_HASH_PTR, RegId::SP), //REG_HASH_PTR = stack pointer
+            op::cfei(32),                       //extends current call frame stack by 32 bytes
+        ],
+        script_hash_check(REF_DATA_START_PTR, true, JMP_PREDICATE_FAILURE),
+        vec![
+            //confirm that no other messages with data are included
+            op::gtf(
+                REG_INPUT_INDEX,
+                RegId::ZERO,
+                GTFArgs::ScriptInputsCount.into(),
+            ), //REG_INPUT_INDEX = the number of inputs in the script
+        ],
+        data_messages_check(JMP_LOOP_START, JMP_SKIP_DATA_CHECK, JMP_PREDICATE_FAILURE),
+        vec![
+            op::ret(RegId::ONE),
+            //PREDICATE_FAILURE:
+            op::ret(RegId::ZERO),
+            //referenced data (expected script hash)
+            //00000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000
+        ],
+    ]
+    .concat()
+    .into_iter()
+    .flat_map(Instruction::to_bytes)
+    .collect();
+
+    //add referenced data (expected script hash)
+    predicate.append(&mut script_hash.to_vec());
+    predicate
+}
+
+// Gets the bytecode for a refundable message-to-contract predicate that requires either the
+// relay script or the refund script with the given hashes
+pub fn refundable_bytecode_for_scripts(
+    relay_script_hash: [u8; 32],
+    refund_script_hash: [u8; 32],
+) -> Vec<u8> {
+    //instruction jump points
+    const JMP_CHECK_REFUND_SCRIPT: u16 = 10;
+    const JMP_CHECK_INPUTS: u16 = 13;
+    const JMP_LOOP_START: u16 = 15;
+    const JMP_SKIP_DATA_CHECK: u16 = 20;
+    const JMP_PREDICATE_FAILURE: u16 = 22;
+
+    //referenced data start pointer
+    const REF_DATA_START_PTR: u16 = 23 * BYTES_PER_INSTR;
+
+    /* The following assembly code is intended to do the following:
+     *  -Verify that the script bytecode hash for the transaction matches that of
+     *   either the expected Message to Contract script or the expected refund script
+     *  -Verify there are no other `InputMessages` with data in the transaction
+     *   other than the first input
+     *
+     * If these conditions are met, then the predicate evaluates as true.
+     */
+    let mut predicate: Vec<u8> = [
+        vec![
+            //extend stack for storing script hash
+            op::move_(REG_HASH_PTR, RegId::SP), //REG_HASH_PTR = stack pointer
+            op::cfei(32),                       //extends current call frame stack by 32 bytes
+        ],
+        //compare hash with expected relay script hash
+        script_hash_check(REF_DATA_START_PTR, true, JMP_CHECK_REFUND_SCRIPT),
+        vec![
+            op::ji(JMP_CHECK_INPUTS as u32), //jumps to CHECK_INPUTS
+            //CHECK_REFUND_SCRIPT:
+            op::addi(REG_EXPECTED_HASH_PTR, REG_EXPECTED_HASH_PTR, 32), //REG_EXPECTED_HASH_PTR = address of the second hash in the reference data
+            op::meq(REG_RESULT, REG_EXPECTED_HASH_PTR, REG_HASH_PTR, REG_VAL_32), //REG_RESULT = if the 32bytes at REG_HASH_PTR equals the 32bytes at REG_EXPECTED_HASH_PTR
+            op::jnei(REG_RESULT, RegId::ONE, JMP_PREDICATE_FAILURE), //jumps to PREDICATE_FAILURE if REG_RESULT is not 1
+            //CHECK_INPUTS:
+            //confirm that no other messages with data are included
+            op::gtf(
+                REG_INPUT_INDEX,
+                RegId::ZERO,
+                GTFArgs::ScriptInputsCount.into(),
+            ), //REG_INPUT_INDEX = the number of inputs in the script
+        ],
+        data_messages_check(JMP_LOOP_START, JMP_SKIP_DATA_CHECK, JMP_PREDICATE_FAILURE),
+        vec![
+            op::ret(RegId::ONE),
+            //PREDICATE_FAILURE:
+            op::ret(RegId::ZERO),
+            //referenced data (expected relay script hash, expected refund script hash)
+            //00000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000
+            //00000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000
+        ],
+    ]
+    .concat()
+    .into_iter()
+    .flat_map(Instruction::to_bytes)
+    .collect();
+
+    //add referenced data (expected relay script hash, expected refund script hash)
+    predicate.append(&mut relay_script_hash.to_vec());
+    predicate.append(&mut refund_script_hash.to_vec());
+    predicate
+}
+
+// Gets the bytecode for a time-locked message-to-contract predicate that requires the script with
+// the given hash and a transaction maturity of at least the minimum block height in the message data
+pub fn time_locked_bytecode_for_script(script_hash: [u8; 32]) -> Vec<u8> {
+    //register names
+    const REG_MSG_DATA_PTR: u8 = 0x1b;
+    const REG_MIN_HEIGHT: u8 = 0x1d;
+    const REG_MATURITY: u8 = 0x1e;
+
+    //instruction jump points
+    const JMP_LOOP_START: u16 = 20;
+    const JMP_SKIP_DATA_CHECK: u16 = 25;
+    const JMP_PREDICATE_FAILURE: u16 = 27;
+
+    //referenced data start pointer
+    const REF_DATA_START_PTR: u16 = 28 * BYTES_PER_INSTR;
+
+    /* The following assembly code is intended to do the following:
+     *  -Verify that the transaction maturity is at least the minimum block height
+     *   found at bytes 32..40 of the data of the first input message
+     *  -Verify that the script bytecode hash for the transaction matches that of
+     *   the expected Message to Contract script
+     *  -Verify there are no other `InputMessages` with data in the transaction
+     *   other than the first input
+     *
+     * If these conditions are met, then the predicate evaluates as true.
+     */
+    let mut predicate: Vec<u8> = [
+        //check the message data holds a minimum block height [contract id, minimum block height]
+        message_data_len_check(TIME_LOCK_HEADER_LEN, JMP_PREDICATE_FAILURE),
+        vec![
+            //check the transaction maturity against the minimum block height
+            op::lw(REG_MIN_HEIGHT, REG_MSG_DATA_PTR, 4), //REG_MIN_HEIGHT = word at REG_MSG_DATA_PTR + 32bytes
+            op::gtf(REG_MATURITY, RegId::ZERO, GTFArgs::ScriptMaturity.into()), //REG_MATURITY = the maturity of the transaction
+            op::lt(REG_RESULT, REG_MATURITY, REG_MIN_HEIGHT), //REG_RESULT = if REG_MATURITY is less than REG_MIN_HEIGHT
+            op::jnei(REG_RESULT, RegId::ZERO, JMP_PREDICATE_FAILURE), //jumps to PREDICATE_FAILURE if REG_RESULT is not 0
+            //extend stack for storing script hash
+            op::move_(REG_HASH_PTR, RegId::SP), //REG_HASH_PTR = stack pointer
+            op::cfei(32),                       //extends current call frame stack by 32 bytes
+        ],
+        script_hash_check(REF_DATA_START_PTR, true, JMP_PREDICATE_FAILURE),
+        vec![
+            //confirm that no other messages with data are included
+            op::gtf(
+                REG_INPUT_INDEX,
+                RegId::ZERO,
+                GTFArgs::ScriptInputsCount.into(),
+            ), //REG_INPUT_INDEX = the number of inputs in the script
+        ],
+        data_messages_check(JMP_LOOP_START, JMP_SKIP_DATA_CHECK, JMP_PREDICATE_FAILURE),
+        vec![
+            op::ret(RegId::ONE),
+            //PREDICATE_FAILURE:
+            op::ret(RegId::ZERO),
+            //referenced data (expected script hash)
+            //00000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000
+        ],
+    ]
+    .concat()
+    .into_iter()
+    .flat_map(Instruction::to_bytes)
+    .collect();
+
+    //add referenced data (expected script hash)
+    predicate.append(&mut script_hash.to_vec());
+    predicate
+}
+
+// Gets the bytecode for an allowlist message-to-contract predicate that requires the script with
+// the given hash and a Merkle proof (as predicate data) that the target contract is in the allowlist
+pub fn allowlist_bytecode_for_script(script_hash: [u8; 32], allowlist_root: [u8; 32]) -> Vec<u8> {
+    //register names
+    const REG_NODE_PTR: u8 = 0x1b;
+    const REG_SIBLING_SLOT_PTR: u8 = 0x1c;
+    const REG_MSG_DATA_PTR: u8 = 0x1d;
+    const REG_PROOF_PTR: u8 = 0x1e;
+    const REG_PROOF_LEN: u8 = 0x1f;
+    const REG_SIBLING_COUNT: u8 = 0x20;
+    const REG_LEAF_INDEX: u8 = 0x21;
+    const REG_EXPECTED_LEN: u8 = 0x22;
+    const REG_SIBLING_PTR: u8 = 0x23;
+    const REG_VAL_64: u8 = 0x24;
+    const REG_ROOT_PTR: u8 = 0x25;
+
+    //instruction jump points
+    const JMP_PROOF_LOOP_START: u16 = 18;
+    const JMP_PROOF_LOOP_BODY: u16 = 20;
+    const JMP_NODE_IS_RIGHT: u16 = 24;
+    const JMP_HASH_NODE: u16 = 26;
+    const JMP_PROOF_LOOP_END: u16 = 31;
+    const JMP_LOOP_START: u16 = 44;
+    const JMP_SKIP_DATA_CHECK: u16 = 49;
+    const JMP_PREDICATE_FAILURE: u16 = 51;
+
+    //referenced data start pointer
+    const REF_DATA_START_PTR: u16 = 52 * BYTES_PER_INSTR;
+
+    /* The following assembly code is intended to do the following:
+     *  -Verify that the predicate data of the first input is a Merkle proof that the
+     *   target contract (the first 32 bytes of its message data) is in the allowlist
+     *  -Verify that the script bytecode hash for the transaction matches that of
+     *   the expected Message to Contract script
+     *  -Verify there are no other `InputMessages` with data in the transaction
+     *   other than the first input
+     *
+     * If these conditions are met, then the predicate evaluates as true.
+     */
+    let mut predicate: Vec<u8> = [
+        vec![
+            //extend stack for storing a node and its sibling
+            op::move_(REG_NODE_PTR, RegId::SP), //REG_NODE_PTR = stack pointer
+            op::cfei(32 + 32), //extends current call frame stack by 32+32 bytes [node, sibling]
+            //compute leaf hash of the target contract
+            op::gtf(
+                REG_MSG_DATA_PTR,
+                RegId::ZERO,
+                GTFArgs::InputMessageData.into(),
+            ), //REG_MSG_DATA_PTR = memory location of the message data from input[0]
+            op::gtf(
+                REG_INPUT_MSG_DATA_LEN,
+                RegId::ZERO,
+                GTFArgs::InputMessageDataLength.into(),
+            ), //REG_INPUT_MSG_DATA_LEN = the data length of input[0]
+            op::movi(REG_VAL_32, 32), //REG_VAL_32 = 32
+            op::lt(REG_RESULT, REG_INPUT_MSG_DATA_LEN, REG_VAL_32), //REG_RESULT = if REG_INPUT_MSG_DATA_LEN is less than 32
+            op::jnei(REG_RESULT, RegId::ZERO, JMP_PREDICATE_FAILURE), //jumps to PREDICATE_FAILURE if REG_RESULT is not 0
+            op::s256(REG_NODE_PTR, REG_MSG_DATA_PTR, REG_VAL_32), //32bytes at REG_NODE_PTR = hash of the target contract id
+            //read proof [sibling count, leaf index, siblings]
+            op::gtf(
+                REG_PROOF_PTR,
+                RegId::ZERO,
+                GTFArgs::InputMessagePredicateData.into(),
+            ), //REG_PROOF_PTR = memory location of the predicate data from input[0]
+            op::gtf(
+                REG_PROOF_LEN,
+                RegId::ZERO,
+                GTFArgs::InputMessagePredicateDataLength.into(),
+            ), //REG_PROOF_LEN = the predicate data length of input[0]
+            op::lw(REG_SIBLING_COUNT, REG_PROOF_PTR, 0), //REG_SIBLING_COUNT = word at REG_PROOF_PTR
+            op::lw(REG_LEAF_INDEX, REG_PROOF_PTR, 1), //REG_LEAF_INDEX = word at REG_PROOF_PTR + 8bytes
+            op::muli(REG_EXPECTED_LEN, REG_SIBLING_COUNT, 32), //REG_EXPECTED_LEN = REG_SIBLING_COUNT * 32
+            op::addi(REG_EXPECTED_LEN, REG_EXPECTED_LEN, 8 + 8), //REG_EXPECTED_LEN = REG_EXPECTED_LEN + 16bytes
+            op::jnei(REG_EXPECTED_LEN, REG_PROOF_LEN, JMP_PREDICATE_FAILURE), //jumps to PREDICATE_FAILURE if REG_EXPECTED_LEN does not equal REG_PROOF_LEN
+            op::addi(REG_SIBLING_PTR, REG_PROOF_PTR, 8 + 8), //REG_SIBLING_PTR = REG_PROOF_PTR + 16bytes [first sibling]
+            op::addi(REG_SIBLING_SLOT_PTR, REG_NODE_PTR, 32), //REG_SIBLING_SLOT_PTR = REG_NODE_PTR + 32bytes
+            op::movi(REG_VAL_64, 64),                         //REG_VAL_64 = 64
+            //PROOF_LOOP_START:
+            op::jnzi(REG_SIBLING_COUNT, JMP_PROOF_LOOP_BODY as u32), //jumps to PROOF_LOOP_BODY if REG_SIBLING_COUNT is not 0
+            op::ji(JMP_PROOF_LOOP_END as u32),                       //jumps to PROOF_LOOP_END
+            //PROOF_LOOP_BODY:
+            op::andi(REG_RESULT, REG_LEAF_INDEX, 1), //REG_RESULT = lowest bit of REG_LEAF_INDEX
+            op::jnzi(REG_RESULT, JMP_NODE_IS_RIGHT as u32), //jumps to NODE_IS_RIGHT if REG_RESULT is not 0
+            op::mcpi(REG_SIBLING_SLOT_PTR, REG_SIBLING_PTR, 32), //32 bytes at REG_SIBLING_SLOT_PTR = the 32 bytes at REG_SIBLING_PTR
+            op::ji(JMP_HASH_NODE as u32),                        //jumps to HASH_NODE
+            //NODE_IS_RIGHT:
+            op::mcpi(REG_SIBLING_SLOT_PTR, REG_NODE_PTR, 32), //32 bytes at REG_SIBLING_SLOT_PTR = the 32 bytes at REG_NODE_PTR
+            op::mcpi(REG_NODE_PTR, REG_SIBLING_PTR, 32), //32 bytes at REG_NODE_PTR = the 32 bytes at REG_SIBLING_PTR
+            //HASH_NODE:
+            op::s256(REG_NODE_PTR, REG_NODE_PTR, REG_VAL_64), //32bytes at REG_NODE_PTR = hash of the 64 bytes at REG_NODE_PTR
+            op::srli(REG_LEAF_INDEX, REG_LEAF_INDEX, 1),      //REG_LEAF_INDEX = REG_LEAF_INDEX >> 1
+            op::addi(REG_SIBLING_PTR, REG_SIBLING_PTR, 32), //REG_SIBLING_PTR = REG_SIBLING_PTR + 32bytes [next sibling]
+            op::subi(REG_SIBLING_COUNT, REG_SIBLING_COUNT, 1), //REG_SIBLING_COUNT = REG_SIBLING_COUNT - 1
+            op::ji(JMP_PROOF_LOOP_START as u32),               //jumps back to PROOF_LOOP_START
+            //PROOF_LOOP_END:
+            op::addi(REG_ROOT_PTR, RegId::IS, REF_DATA_START_PTR + 32), //REG_ROOT_PTR = address of the allowlist root in the reference data
+            op::meq(REG_RESULT, REG_NODE_PTR, REG_ROOT_PTR, REG_VAL_32), //REG_RESULT = if the 32bytes at REG_NODE_PTR equals the 32bytes at REG_ROOT_PTR
+            op::jnei(REG_RESULT, RegId::ONE, JMP_PREDICATE_FAILURE), //jumps to PREDICATE_FAILURE if REG_RESULT is not 1
+            //extend stack for storing script hash
+            op::move_(REG_HASH_PTR, RegId::SP), //REG_HASH_PTR = stack pointer
+            op::cfei(32),                       //extends current call frame stack by 32 bytes
+        ],
+        script_hash_check(REF_DATA_START_PTR, false, JMP_PREDICATE_FAILURE),
+        vec![
+            //confirm that no other messages with data are included
+            op::gtf(
+                REG_INPUT_INDEX,
+                RegId::ZERO,
+                GTFArgs::ScriptInputsCount.into(),
+            ), //REG_INPUT_INDEX = the number of inputs in the script
+        ],
+        data_messages_check(JMP_LOOP_START, JMP_SKIP_DATA_CHECK, JMP_PREDICATE_FAILURE),
+        vec![
+            op::ret(RegId::ONE),
+            //PREDICATE_FAILURE:
+            op::ret(RegId::ZERO),
+            //referenced data (expected script hash, allowlist root)
+            //00000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000
+            //00000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000
+        ],
+    ]
+    .concat()
+    .into_iter()
+    .flat_map(Instruction::to_bytes)
+    .collect();
+
+    //add referenced data (expected script hash, allowlist root)
+    predicate.append(&mut script_hash.to_vec());
+    predicate.append(&mut allowlist_root.to_vec());
+    predicate
+}
+
+// Gets the bytecode for a strict message-to-contract predicate that requires the script with the
+// given hash and a transaction layout of [message, target contract, extra inputs..] with at most
+// `max_inputs` inputs, extra inputs of the types in `extra_input_types_mask` (bit `1 << type`)
+// and the first output being the contract output of the target contract
+pub fn strict_bytecode_for_script(
+    script_hash: [u8; 32],
+    max_inputs: u8,
+    extra_input_types_mask: u8,
+) -> Vec<u8> {
+    //register names
+    const REG_INPUT_COUNT: u8 = 0x1b;
+    const REG_TEMP: u8 = 0x1c;
+    const REG_CONTRACT_ID_PTR: u8 = 0x1d;
+    const REG_MSG_DATA_PTR: u8 = 0x1e;
+    const REG_TYPES_MASK: u8 = 0x1f;
+    const REG_VAL_2: u8 = 0x20;
+
+    //instruction jump points
+    const JMP_EXTRA_LOOP_START: u16 = 24;
+    const JMP_EXTRA_LOOP_BODY: u16 = 26;
+    const JMP_EXTRA_LOOP_END: u16 = 32;
+    const JMP_LOOP_START: u16 = 42;
+    const JMP_SKIP_DATA_CHECK: u16 = 47;
+    const JMP_PREDICATE_FAILURE: u16 = 49;
+
+    //referenced data start pointer
+    const REF_DATA_START_PTR: u16 = 50 * BYTES_PER_INSTR;
+
+    /* The following assembly code is intended to do the following:
+     *  -Verify that the transaction has between 2 and the maximum number of inputs
+     *  -Verify that the second input is the contract input of the target contract
+     *   (the first 32 bytes of the message data) and that the first output is the
+     *   contract output for it
+     *  -Verify that all other inputs are of the allowed types
+     *  -Verify that the script bytecode hash for the transaction matches that of
+     *   the expected Message to Contract script
+     *  -Verify there are no other `InputMessages` with data in the transaction
+     *   other than the first input
+     *
+     * If these conditions are met, then the predicate evaluates as true.
+     */
+    let mut predicate: Vec<u8> = [
+        vec![
+            //check the number of inputs
+            op::gtf(
+                REG_INPUT_COUNT,
+                RegId::ZERO,
+                GTFArgs::ScriptInputsCount.into(),
+            ), //REG_INPUT_COUNT = the number of inputs in the script
+            op::movi(REG_TEMP, max_inputs as u32), //REG_TEMP = max inputs
+            op::gt(REG_RESULT, REG_INPUT_COUNT, REG_TEMP), //REG_RESULT = if REG_INPUT_COUNT is greater than REG_TEMP
+            op::jnei(REG_RESULT, RegId::ZERO, JMP_PREDICATE_FAILURE), //jumps to PREDICATE_FAILURE if REG_RESULT is not 0
+            op::movi(REG_TEMP, 2), //REG_TEMP = 2 [message, target contract]
+            op::lt(REG_RESULT, REG_INPUT_COUNT, REG_TEMP), //REG_RESULT = if REG_INPUT_COUNT is less than REG_TEMP
+            op::jnei(REG_RESULT, RegId::ZERO, JMP_PREDICATE_FAILURE), //jumps to PREDICATE_FAILURE if REG_RESULT is not 0
+            //check the target contract input
+            op::gtf(REG_INPUT_TYPE, RegId::ONE, GTFArgs::InputType.into()), //REG_INPUT_TYPE = the type of input[1]
+            op::jnei(REG_INPUT_TYPE, RegId::ONE, JMP_PREDICATE_FAILURE), //jumps to PREDICATE_FAILURE if REG_INPUT_TYPE is not INPUT_CONTRACT_TYPE (1)
+            op::gtf(REG_CONTRACT_ID_PTR, RegId::ONE, GTF_INPUT_CONTRACT_ID), //REG_CONTRACT_ID_PTR = memory location of the contract id of input[1]
+            op::gtf(
+                REG_MSG_DATA_PTR,
+                RegId::ZERO,
+                GTFArgs::InputMessageData.into(),
+            ), //REG_MSG_DATA_PTR = memory location of the message data from input[0]
+            op::gtf(
+                REG_INPUT_MSG_DATA_LEN,
+                RegId::ZERO,
+                GTFArgs::InputMessageDataLength.into(),
+            ), //REG_INPUT_MSG_DATA_LEN = the data length of input[0]
+            op::movi(REG_VAL_32, 32),                                        //REG_VAL_32 = 32
+            op::lt(REG_RESULT, REG_INPUT_MSG_DATA_LEN, REG_VAL_32), //REG_RESULT = if REG_INPUT_MSG_DATA_LEN is less than 32
+            op::jnei(REG_RESULT, RegId::ZERO, JMP_PREDICATE_FAILURE), //jumps to PREDICATE_FAILURE if REG_RESULT is not 0
+            op::meq(
+                REG_RESULT,
+                REG_CONTRACT_ID_PTR,
+                REG_MSG_DATA_PTR,
+                REG_VAL_32,
+            ), //REG_RESULT = if the 32bytes at REG_CONTRACT_ID_PTR equals the 32bytes at REG_MSG_DATA_PTR
+            op::jnei(REG_RESULT, RegId::ONE, JMP_PREDICATE_FAILURE), //jumps to PREDICATE_FAILURE if REG_RESULT is not 1
+            //check the target contract output
+            op::gtf(REG_TEMP, RegId::ZERO, GTFArgs::OutputType.into()), //REG_TEMP = the type of output[0]
+            op::jnei(REG_TEMP, RegId::ONE, JMP_PREDICATE_FAILURE), //jumps to PREDICATE_FAILURE if REG_TEMP is not OUTPUT_CONTRACT_TYPE (1)
+            op::gtf(
+                REG_TEMP,
+                RegId::ZERO,
+                GTFArgs::OutputContractInputIndex.into(),
+            ), //REG_TEMP = the input index of output[0]
+            op::jnei(REG_TEMP, RegId::ONE, JMP_PREDICATE_FAILURE), //jumps to PREDICATE_FAILURE if REG_TEMP does not equal 1
+            //check the types of the extra inputs
+            op::movi(REG_TYPES_MASK, extra_input_types_mask as u32), //REG_TYPES_MASK = allowed extra input types
+            op::movi(REG_VAL_2, 2),                                  //REG_VAL_2 = 2
+            op::move_(REG_INPUT_INDEX, REG_INPUT_COUNT), //REG_INPUT_INDEX = REG_INPUT_COUNT
+            //EXTRA_LOOP_START:
+            op::jnei(REG_INPUT_INDEX, REG_VAL_2, JMP_EXTRA_LOOP_BODY), //jumps to EXTRA_LOOP_BODY if REG_INPUT_INDEX does not equal 2
+            op::ji(JMP_EXTRA_LOOP_END as u32),                         //jumps to EXTRA_LOOP_END
+            //EXTRA_LOOP_BODY:
+            op::subi(REG_INPUT_INDEX, REG_INPUT_INDEX, 1), //REG_INPUT_INDEX = REG_INPUT_INDEX - 1
+            op::gtf(REG_INPUT_TYPE, REG_INPUT_INDEX, GTFArgs::InputType.into()), //REG_INPUT_TYPE = the type of input for input[INPUT_INDEX]
+            op::sll(REG_TEMP, RegId::ONE, REG_INPUT_TYPE), //REG_TEMP = 1 << REG_INPUT_TYPE
+            op::and(REG_RESULT, REG_TEMP, REG_TYPES_MASK), //REG_RESULT = REG_TEMP & REG_TYPES_MASK
+            op::jnzi(REG_RESULT, JMP_EXTRA_LOOP_START as u32), //jumps back to EXTRA_LOOP_START if REG_RESULT is not 0
+            op::ji(JMP_PREDICATE_FAILURE as u32),              //jumps to PREDICATE_FAILURE
+            //EXTRA_LOOP_END:
+            //extend stack for storing script hash
+            op::move_(REG_HASH_PTR, RegId::SP), //REG_HASH_PTR = stack pointer
+            op::cfei(32),                       //extends current call frame stack by 32 bytes
+        ],
+        script_hash_check(REF_DATA_START_PTR, false, JMP_PREDICATE_FAILURE),
+        vec![
+            //confirm that no other messages with data are included
+            op::move_(REG_INPUT_INDEX, REG_INPUT_COUNT), //REG_INPUT_INDEX = the number of inputs in the script
+        ],
+        data_messages_check(JMP_LOOP_START, JMP_SKIP_DATA_CHECK, JMP_PREDICATE_FAILURE),
+        vec![
+            op::ret(RegId::ONE),
+            //PREDICATE_FAILURE:
+            op::ret(RegId::ZERO),
+            //referenced data (expected script hash)
+            //00000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000
+        ],
+    ]
+    .concat()
+    .into_iter()
+    .flat_map(Instruction::to_bytes)
+    .collect();
+
+    //add referenced data (expected script hash)
+    predicate.append(&mut script_hash.to_vec());
+    predicate
+}
+
+// Gets the bytecode for a message-to-contract predicate that requires the script with the given
+// hash and only allows outputs through which the message amount cannot leave the target contract
+pub fn sealed_outputs_bytecode_for_script(script_hash: [u8; 32]) -> Vec<u8> {
+    //register names
+    const REG_INPUT_COUNT: u8 = 0x1b;
+    const REG_OUTPUT_INDEX: u8 = 0x1c;
+    const REG_OUTPUT_TYPE: u8 = 0x1d;
+    const REG_TEMP: u8 = 0x1e;
+    const REG_BASE_ASSET_PTR: u8 = 0x1f;
+    const REG_ASSET_PTR: u8 = 0x20;
+    const REG_TO_PTR: u8 = 0x21;
+    const REG_OWNER_PTR: u8 = 0x22;
+
+    //instruction jump points
+    const JMP_OUTPUT_LOOP_START: u16 = 6;
+    const JMP_OUTPUT_LOOP_BODY: u16 = 8;
+    const JMP_CHECK_VARIABLE: u16 = 12;
+    const JMP_CHECK_CHANGE: u16 = 20;
+    const JMP_OWNER_LOOP_START: u16 = 24;
+    const JMP_OWNER_LOOP_BODY: u16 = 26;
+    const JMP_OUTPUT_LOOP_END: u16 = 33;
+    const JMP_LOOP_START: u16 = 41;
+    const JMP_SKIP_DATA_CHECK: u16 = 46;
+    const JMP_PREDICATE_FAILURE: u16 = 48;
+
+    //referenced data start pointer
+    const REF_DATA_START_PTR: u16 = 49 * BYTES_PER_INSTR;
+
+    /* The following assembly code is intended to do the following:
+     *  -Verify that all outputs are either contract outputs, variable outputs,
+     *   coin outputs of assets other than the base asset or change outputs to the
+     *   owner of one of the coin inputs
+     *  -Verify that the script bytecode hash for the transaction matches that of
+     *   the expected Message to Contract script
+     *  -Verify there are no other `InputMessages` with data in the transaction
+     *   other than the first input
+     *
+     * If these conditions are met, then the predicate evaluates as true.
+     *
+     * note: this code makes the assumption that all memory at VM initialization is set to 0
+     */
+    let mut predicate: Vec<u8> = [
+        vec![
+            //extend stack for storing script hash and base asset id
+            op::move_(REG_HASH_PTR, RegId::SP), //REG_HASH_PTR = stack pointer
+            op::cfei(32 + 32), //extends current call frame stack by 32+32 bytes [script hash, base asset id]
+            op::addi(REG_BASE_ASSET_PTR, REG_HASH_PTR, 32), //REG_BASE_ASSET_PTR = REG_HASH_PTR + 32bytes
+            op::movi(REG_VAL_32, 32),                       //REG_VAL_32 = 32
+            op::gtf(
+                REG_INPUT_COUNT,
+                RegId::ZERO,
+                GTFArgs::ScriptInputsCount.into(),
+            ), //REG_INPUT_COUNT = the number of inputs in the script
+            //check the outputs
+            op::gtf(
+                REG_OUTPUT_INDEX,
+                RegId::ZERO,
+                GTFArgs::ScriptOutputsCount.into(),
+            ), //REG_OUTPUT_INDEX = the number of outputs in the script
+            //OUTPUT_LOOP_START:
+            op::jnei(REG_OUTPUT_INDEX, RegId::ZERO, JMP_OUTPUT_LOOP_BODY), //jumps to OUTPUT_LOOP_BODY if REG_OUTPUT_INDEX is not 0
+            op::ji(JMP_OUTPUT_LOOP_END as u32), //jumps to OUTPUT_LOOP_END
+            //OUTPUT_LOOP_BODY:
+            op::subi(REG_OUTPUT_INDEX, REG_OUTPUT_INDEX, 1), //REG_OUTPUT_INDEX = REG_OUTPUT_INDEX - 1
+            op::gtf(
+                REG_OUTPUT_TYPE,
+                REG_OUTPUT_INDEX,
+                GTFArgs::OutputType.into(),
+            ), //REG_OUTPUT_TYPE = the type of output[OUTPUT_INDEX]
+            //contract outputs are allowed
+            op::jnei(REG_OUTPUT_TYPE, RegId::ONE, JMP_CHECK_VARIABLE), //jumps to CHECK_VARIABLE if REG_OUTPUT_TYPE is not OUTPUT_CONTRACT_TYPE (1)
+            op::ji(JMP_OUTPUT_LOOP_START as u32), //jumps back to OUTPUT_LOOP_START
+            //CHECK_VARIABLE:
+            op::movi(REG_TEMP, OUTPUT_VARIABLE_TYPE), //REG_TEMP = OUTPUT_VARIABLE_TYPE
+            op::eq(REG_RESULT, REG_OUTPUT_TYPE, REG_TEMP), //REG_RESULT = if REG_OUTPUT_TYPE equals REG_TEMP
+            op::jnzi(REG_RESULT, JMP_OUTPUT_LOOP_START as u32), //jumps back to OUTPUT_LOOP_START if REG_RESULT is not 0
+            //coin outputs are allowed for assets other than the base asset
+            op::jnei(REG_OUTPUT_TYPE, RegId::ZERO, JMP_CHECK_CHANGE), //jumps to CHECK_CHANGE if REG_OUTPUT_TYPE is not OUTPUT_COIN_TYPE (0)
+            op::gtf(
+                REG_ASSET_PTR,
+                REG_OUTPUT_INDEX,
+                GTFArgs::OutputCoinAssetId.into(),
+            ), //REG_ASSET_PTR = memory location of the asset id of output[OUTPUT_INDEX]
+            op::meq(REG_RESULT, REG_ASSET_PTR, REG_BASE_ASSET_PTR, REG_VAL_32), //REG_RESULT = if the 32bytes at REG_ASSET_PTR equals the 32bytes at REG_BASE_ASSET_PTR
+            op::jnzi(REG_RESULT, JMP_PREDICATE_FAILURE as u32), //jumps to PREDICATE_FAILURE if REG_RESULT is not 0
+            op::ji(JMP_OUTPUT_LOOP_START as u32),               //jumps back to OUTPUT_LOOP_START
+            //CHECK_CHANGE:
+            op::movi(REG_TEMP, OUTPUT_CHANGE_TYPE), //REG_TEMP = OUTPUT_CHANGE_TYPE
+            op::jnei(REG_OUTPUT_TYPE, REG_TEMP, JMP_PREDICATE_FAILURE), //jumps to PREDICATE_FAILURE if REG_OUTPUT_TYPE does not equal REG_TEMP
+            //change outputs are allowed to the owner of a coin input
+            op::gtf(REG_TO_PTR, REG_OUTPUT_INDEX, GTFArgs::OutputCoinTo.into()), //REG_TO_PTR = memory location of the recipient of output[OUTPUT_INDEX]
+            op::move_(REG_INPUT_INDEX, REG_INPUT_COUNT), //REG_INPUT_INDEX = REG_INPUT_COUNT
+            //OWNER_LOOP_START:
+            op::jnei(REG_INPUT_INDEX, RegId::ZERO, JMP_OWNER_LOOP_BODY), //jumps to OWNER_LOOP_BODY if REG_INPUT_INDEX is not 0
+            op::ji(JMP_PREDICATE_FAILURE as u32), //jumps to PREDICATE_FAILURE
+            //OWNER_LOOP_BODY:
+            op::subi(REG_INPUT_INDEX, REG_INPUT_INDEX, 1), //REG_INPUT_INDEX = REG_INPUT_INDEX - 1
+            op::gtf(REG_INPUT_TYPE, REG_INPUT_INDEX, GTFArgs::InputType.into()), //REG_INPUT_TYPE = the type of input for input[INPUT_INDEX]
+            op::jnei(REG_INPUT_TYPE, RegId::ZERO, JMP_OWNER_LOOP_START), //jumps back to OWNER_LOOP_START if REG_INPUT_TYPE is not INPUT_COIN_TYPE (0)
+            op::gtf(
+                REG_OWNER_PTR,
+                REG_INPUT_INDEX,
+                GTFArgs::InputCoinOwner.into(),
+            ), //REG_OWNER_PTR = memory location of the owner of input[INPUT_INDEX]
+            op::meq(REG_RESULT, REG_OWNER_PTR, REG_TO_PTR, REG_VAL_32), //REG_RESULT = if the 32bytes at REG_OWNER_PTR equals the 32bytes at REG_TO_PTR
+            op::jnzi(REG_RESULT, JMP_OUTPUT_LOOP_START as u32), //jumps back to OUTPUT_LOOP_START if REG_RESULT is not 0
+            op::ji(JMP_OWNER_LOOP_START as u32),                //jumps back to OWNER_LOOP_START
+                                                                //OUTPUT_LOOP_END:
+        ],
+        script_hash_check(REF_DATA_START_PTR, false, JMP_PREDICATE_FAILURE),
+        vec![
+            //confirm that no other messages with data are included
+            op::move_(REG_INPUT_INDEX, REG_INPUT_COUNT), //REG_INPUT_INDEX = the number of inputs in the script
+        ],
+        data_messages_check(JMP_LOOP_START, JMP_SKIP_DATA_CHECK, JMP_PREDICATE_FAILURE),
+        vec![
+            op::ret(RegId::ONE),
+            //PREDICATE_FAILURE:
+            op::ret(RegId::ZERO),
+            //referenced data (expected script hash)
+            //00000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000
+        ],
+    ]
+    .concat()
+    .into_iter()
+    .flat_map(Instruction::to_bytes)
+    .collect();
+
+    //add referenced data (expected script hash)
+    predicate.append(&mut script_hash.to_vec());
+    predicate
+}
+
+// Gets the bytecode for a relayer-authorized message-to-contract predicate that requires the script
+// with the given hash and a signature over the transaction ID by the relayer in the message data
+pub fn relayer_bytecode_for_script(script_hash: [u8; 32]) -> Vec<u8> {
+    //instruction jump points
+    const JMP_LOOP_START: u16 = 33;
+    const JMP_SKIP_DATA_CHECK: u16 = 38;
+    const JMP_PREDICATE_FAILURE: u16 = 40;
+
+    //referenced data start pointer
+    const REF_DATA_START_PTR: u16 = 41 * BYTES_PER_INSTR;
+
+    /* The following assembly code is intended to do the following:
+     *  -Verify that the predicate data of the first input is the index of a 64 byte
+     *   witness holding a signature over the transaction ID (at memory address 0)
+     *  -Verify that the address of the signer matches the relayer address found at
+     *   bytes 32..64 of the data of the first input message
+     *  -Verify that the script bytecode hash for the transaction matches that of
+     *   the expected Message to Contract script
+     *  -Verify there are no other `InputMessages` with data in the transaction
+     *   other than the first input
+     *
+     * If these conditions are met, then the predicate evaluates as true.
+     */
+    let mut predicate: Vec<u8> = [
+        //check the message data holds a relayer address [contract id, relayer address]
+        message_data_len_check(RELAYER_HEADER_LEN, JMP_PREDICATE_FAILURE),
+        relayer_signature_check(true, JMP_PREDICATE_FAILURE),
+        vec![
+            //extend stack for storing script hash
+            op::move_(REG_HASH_PTR, RegId::SP), //REG_HASH_PTR = stack pointer
+            op::cfei(32),                       //extends current call frame stack by 32 bytes
+        ],
+        script_hash_check(REF_DATA_START_PTR, false, JMP_PREDICATE_FAILURE),
+        vec![
+            //confirm that no other messages with data are included
+            op::gtf(
+                REG_INPUT_INDEX,
+                RegId::ZERO,
+                GTFArgs::ScriptInputsCount.into(),
+            ), //REG_INPUT_INDEX = the number of inputs in the script
+        ],
+        data_messages_check(JMP_LOOP_START, JMP_SKIP_DATA_CHECK, JMP_PREDICATE_FAILURE),
+        vec![
+            op::ret(RegId::ONE),
+            //PREDICATE_FAILURE:
+            op::ret(RegId::ZERO),
+            //referenced data (expected script hash)
+            //00000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000
+        ],
+    ]
+    .concat()
+    .into_iter()
+    .flat_map(Instruction::to_bytes)
+    .collect();
+
+    //add referenced data (expected script hash)
+    predicate.append(&mut script_hash.to_vec());
+    predicate
+}
+
+// Gets the bytecode for a relay window message-to-contract predicate that requires the script with
+// the given hash and, for transactions with a maturity below the deadline in the message data, a
+// signature over the transaction ID by the relayer in the message data
+pub fn relay_window_bytecode_for_script(script_hash: [u8; 32]) -> Vec<u8> {
+    //register names
+    const REG_MSG_DATA_PTR: u8 = 0x1b;
+    const REG_DEADLINE: u8 = 0x26;
+    const REG_MATURITY: u8 = 0x27;
+
+    //instruction jump points
+    const JMP_SCRIPT_CHECK: u16 = 27;
+    const JMP_LOOP_START: u16 = 37;
+    const JMP_SKIP_DATA_CHECK: u16 = 42;
+    const JMP_PREDICATE_FAILURE: u16 = 44;
+
+    //referenced data start pointer
+    const REF_DATA_START_PTR: u16 = 45 * BYTES_PER_INSTR;
+
+    /* The following assembly code is intended to do the following:
+     *  -Verify that the transaction maturity is at least the deadline found at
+     *   bytes 64..72 of the data of the first input message, or otherwise:
+     *    -Verify that the predicate data of the first input is the index of a 64 byte
+     *     witness holding a signature over the transaction ID (at memory address 0)
+     *    -Verify that the address of the signer matches the relayer address found at
+     *     bytes 32..64 of the data of the first input message
+     *  -Verify that the script bytecode hash for the transaction matches that of
+     *   the expected Message to Contract script
+     *  -Verify there are no other `InputMessages` with data in the transaction
+     *   other than the first input
+     *
+     * If these conditions are met, then the predicate evaluates as true.
+     */
+    let mut predicate: Vec<u8> = [
+        //check the message data holds a relayer address and deadline [contract id, relayer address, deadline]
+        message_data_len_check(RELAY_WINDOW_HEADER_LEN, JMP_PREDICATE_FAILURE),
+        vec![
+            op::movi(REG_VAL_32, 32), //REG_VAL_32 = 32
+            //skip the relayer check once the exclusive relay window is over
+            op::lw(REG_DEADLINE, REG_MSG_DATA_PTR, 8), //REG_DEADLINE = word at REG_MSG_DATA_PTR + 64bytes
+            op::gtf(REG_MATURITY, RegId::ZERO, GTFArgs::ScriptMaturity.into()), //REG_MATURITY = the maturity of the transaction
+            op::lt(REG_RESULT, REG_MATURITY, REG_DEADLINE), //REG_RESULT = if REG_MATURITY is less than REG_DEADLINE
+            op::jnei(REG_RESULT, RegId::ONE, JMP_SCRIPT_CHECK), //skips to SCRIPT_CHECK if REG_RESULT is not 1
+        ],
+        relayer_signature_check(false, JMP_PREDICATE_FAILURE),
+        vec![
+            //SCRIPT_CHECK:
+            //extend stack for storing script hash
+            op::move_(REG_HASH_PTR, RegId::SP), //REG_HASH_PTR = stack pointer
+            op::cfei(32),                       //extends current call frame stack by 32 bytes
+        ],
+        script_hash_check(REF_DATA_START_PTR, false, JMP_PREDICATE_FAILURE),
+        vec![
+            //confirm that no other messages with data are included
+            op::gtf(
+                REG_INPUT_INDEX,
+                RegId::ZERO,
+                GTFArgs::ScriptInputsCount.into(),
+            ), //REG_INPUT_INDEX = the number of inputs in the script
+        ],
+        data_messages_check(JMP_LOOP_START, JMP_SKIP_DATA_CHECK, JMP_PREDICATE_FAILURE),
+        vec![
+            op::ret(RegId::ONE),
+            //PREDICATE_FAILURE:
+            op::ret(RegId::ZERO),
+            //referenced data (expected script hash)
+            //00000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000
+        ],
+    ]
+    .concat()
+    .into_iter()
+    .flat_map(Instruction::to_bytes)
+    .collect();
+
+    //add referenced data (expected script hash)
+    predicate.append(&mut script_hash.to_vec());
+    predicate
+}
+
+// Gets the bytecode for a sender allowlist message-to-contract predicate that requires the script
+// with the given hash and a message sent by one of the given L1 senders
+pub fn sender_allowlist_bytecode_for_script(
+    script_hash: [u8; 32],
+    senders: &[[u8; 32]],
+) -> Vec<u8> {
+    //register names
+    const REG_SENDER_PTR: u8 = 0x1b;
+    const REG_TRUSTED_SENDER_PTR: u8 = 0x1c;
+    const REG_SENDER_COUNT: u8 = 0x1d;
+
+    //instruction jump points
+    const JMP_SENDER_LOOP_START: u16 = 4;
+    const JMP_SENDER_LOOP_BODY: u16 = 6;
+    const JMP_SENDER_FOUND: u16 = 11;
+    const JMP_LOOP_START: u16 = 21;
+    const JMP_SKIP_DATA_CHECK: u16 = 26;
+    const JMP_PREDICATE_FAILURE: u16 = 28;
+
+    //referenced data start pointer
+    const REF_DATA_START_PTR: u16 = 29 * BYTES_PER_INSTR;
+
+    /* The following assembly code is intended to do the following:
+     *  -Verify that the sender of the first input message is one of the trusted
+     *   senders in the reference data
+     *  -Verify that the script bytecode hash for the transaction matches that of
+     *   the expected Message to Contract script
+     *  -Verify there are no other `InputMessages` with data in the transaction
+     *   other than the first input
+     *
+     * If these conditions are met, then the predicate evaluates as true.
+     */
+    let mut predicate: Vec<u8> = [
+        vec![
+            //look for the message sender in the trusted senders
+            op::gtf(
+                REG_SENDER_PTR,
+                RegId::ZERO,
+                GTFArgs::InputMessageSender.into(),
+            ), //REG_SENDER_PTR = memory location of the sender of input[0]
+            op::addi(REG_TRUSTED_SENDER_PTR, RegId::IS, REF_DATA_START_PTR + 32), //REG_TRUSTED_SENDER_PTR = address of the first trusted sender in the reference data
+            op::movi(REG_SENDER_COUNT, senders.len() as u32), //REG_SENDER_COUNT = number of trusted senders
+            op::movi(REG_VAL_32, 32),                         //REG_VAL_32 = 32
+            //SENDER_LOOP_START:
+            op::jnzi(REG_SENDER_COUNT, JMP_SENDER_LOOP_BODY as u32), //jumps to SENDER_LOOP_BODY if REG_SENDER_COUNT is not 0
+            op::ji(JMP_PREDICATE_FAILURE as u32), //jumps to PREDICATE_FAILURE [no trusted sender matched]
+            //SENDER_LOOP_BODY:
+            op::meq(
+                REG_RESULT,
+                REG_SENDER_PTR,
+                REG_TRUSTED_SENDER_PTR,
+                REG_VAL_32,
+            ), //REG_RESULT = if the 32bytes at REG_SENDER_PTR equals the 32bytes at REG_TRUSTED_SENDER_PTR
+            op::jnei(REG_RESULT, RegId::ZERO, JMP_SENDER_FOUND), //jumps to SENDER_FOUND if REG_RESULT is not 0
+            op::addi(REG_TRUSTED_SENDER_PTR, REG_TRUSTED_SENDER_PTR, 32), //REG_TRUSTED_SENDER_PTR = REG_TRUSTED_SENDER_PTR + 32bytes [next trusted sender]
+            op::subi(REG_SENDER_COUNT, REG_SENDER_COUNT, 1), //REG_SENDER_COUNT = REG_SENDER_COUNT - 1
+            op::ji(JMP_SENDER_LOOP_START as u32),            //jumps back to SENDER_LOOP_START
+            //SENDER_FOUND:
+            //extend stack for storing script hash
+            op::move_(REG_HASH_PTR, RegId::SP), //REG_HASH_PTR = stack pointer
+            op::cfei(32),                       //extends current call frame stack by 32 bytes
+        ],
+        script_hash_check(REF_DATA_START_PTR, false, JMP_PREDICATE_FAILURE),
+        vec![
+            //confirm that no other messages with data are included
+            op::gtf(
+                REG_INPUT_INDEX,
+                RegId::ZERO,
+                GTFArgs::ScriptInputsCount.into(),
+            ), //REG_INPUT_INDEX = the number of inputs in the script
+        ],
+        data_messages_check(JMP_LOOP_START, JMP_SKIP_DATA_CHECK, JMP_PREDICATE_FAILURE),
+        vec![
+            op::ret(RegId::ONE),
+            //PREDICATE_FAILURE:
+            op::ret(RegId::ZERO),
+            //referenced data (expected script hash, trusted senders)
+            //00000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000
+            //00000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000
+            //...
+        ],
+    ]
+    .concat()
+    .into_iter()
+    .flat_map(Instruction::to_bytes)
+    .collect();
+
+    //add referenced data (expected script hash, trusted senders)
+    predicate.append(&mut script_hash.to_vec());
+    for sender in senders {
+        predicate.append(&mut sender.to_vec());
+    }
+    predicate
+}
+
+// Gets the bytecode for a recoverable message-to-contract predicate that requires either the relay
+// script with the given hash, or the sweep script with the given hash and outputs that only pay
+// the given recovery address
+pub fn recoverable_bytecode_for_scripts(
+    relay_script_hash: [u8; 32],
+    sweep_script_hash: [u8; 32],
+    recovery_address: [u8; 32],
+) -> Vec<u8> {
+    //register names
+    const REG_RECOVERY_PTR: u8 = 0x1b;
+    const REG_OUTPUT_INDEX: u8 = 0x1c;
+    const REG_OUTPUT_TYPE: u8 = 0x1d;
+    const REG_TEMP: u8 = 0x1e;
+    const REG_TO_PTR: u8 = 0x1f;
+
+    //instruction jump points
+    const JMP_CHECK_SWEEP_SCRIPT: u16 = 18;
+    const JMP_OUTPUT_LOOP_START: u16 = 23;
+    const JMP_OUTPUT_LOOP_BODY: u16 = 25;
+    const JMP_CHECK_TO: u16 = 31;
+    const JMP_SWEEP_INPUTS: u16 = 35;
+    const JMP_SWEEP_LOOP_START: u16 = 37;
+    const JMP_SWEEP_LOOP_BODY: u16 = 39;
+    const JMP_CHECK_INPUTS: u16 = 45;
+    const JMP_LOOP_START: u16 = 47;
+    const JMP_SKIP_DATA_CHECK: u16 = 52;
+    const JMP_PREDICATE_FAILURE: u16 = 54;
+
+    //referenced data start pointer
+    const REF_DATA_START_PTR: u16 = 55 * BYTES_PER_INSTR;
+
+    /* The following assembly code is intended to do the following:
+     *  -Verify that the script bytecode hash for the transaction matches that of
+     *   either the expected Message to Contract script or the expected sweep script
+     *  -For the Message to Contract script, verify that the first input is an
+     *   `InputMessage` with data, that it is the input being verified and there are
+     *   no other `InputMessages` with data in the transaction
+     *  -For the sweep script, verify that all outputs are coin or change outputs to
+     *   the recovery address and that there are no `InputMessages` with data in the
+     *   transaction
+     *
+     * If these conditions are met, then the predicate evaluates as true.
+     */
+    let mut predicate: Vec<u8> = [
+        vec![
+            //extend stack for storing script hash
+            op::move_(REG_HASH_PTR, RegId::SP), //REG_HASH_PTR = stack pointer
+            op::cfei(32),                       //extends current call frame stack by 32 bytes
+        ],
+        //compare hash with expected relay script hash
+        script_hash_check(REF_DATA_START_PTR, true, JMP_CHECK_SWEEP_SCRIPT),
+        vec![
+            //check the first input is a message with data
+            op::gtf(REG_INPUT_TYPE, RegId::ZERO, GTFArgs::InputType.into()), //REG_INPUT_TYPE = the type of input for input[0]
+            op::movi(REG_EXPECTED_INPUT_TYPE, INPUT_MESSAGE_TYPE), //REG_EXPECTED_INPUT_TYPE = REG_INPUT_MESSAGE_TYPE
+            op::jnei(
+                REG_INPUT_TYPE,
+                REG_EXPECTED_INPUT_TYPE,
+                JMP_PREDICATE_FAILURE,
+            ), //jumps to PREDICATE_FAILURE if REG_INPUT_TYPE does not equal REG_EXPECTED_INPUT_TYPE
+            op::gtf(
+                REG_INPUT_MSG_DATA_LEN,
+                RegId::ZERO,
+                GTFArgs::InputMessageDataLength.into(),
+            ), //REG_INPUT_MSG_DATA_LEN = the data length of input[0]
+            op::eq(REG_RESULT, REG_INPUT_MSG_DATA_LEN, RegId::ZERO), //REG_RESULT = if REG_INPUT_MSG_DATA_LEN equals 0
+            op::jnei(REG_RESULT, RegId::ZERO, JMP_PREDICATE_FAILURE), //jumps to PREDICATE_FAILURE if REG_RESULT is not 0
+            //check the input being verified is the first input
+            op::gm(REG_TEMP, GMArgs::GetVerifyingPredicate.into()), //REG_TEMP = index of the input being verified
+            op::jnei(REG_TEMP, RegId::ZERO, JMP_PREDICATE_FAILURE), //jumps to PREDICATE_FAILURE if REG_TEMP is not 0
+            op::ji(JMP_CHECK_INPUTS as u32),                        //jumps to CHECK_INPUTS
+            //CHECK_SWEEP_SCRIPT:
+            op::addi(REG_EXPECTED_HASH_PTR, REG_EXPECTED_HASH_PTR, 32), //REG_EXPECTED_HASH_PTR = address of the second hash in the reference data
+            op::meq(REG_RESULT, REG_EXPECTED_HASH_PTR, REG_HASH_PTR, REG_VAL_32), //REG_RESULT = if the 32bytes at REG_HASH_PTR equals the 32bytes at REG_EXPECTED_HASH_PTR
+            op::jnei(REG_RESULT, RegId::ONE, JMP_PREDICATE_FAILURE), //jumps to PREDICATE_FAILURE if REG_RESULT is not 1
+            //check the outputs only pay the recovery address
+            op::addi(REG_RECOVERY_PTR, REG_EXPECTED_HASH_PTR, 32), //REG_RECOVERY_PTR = address of the recovery address in the reference data
+            op::gtf(
+                REG_OUTPUT_INDEX,
+                RegId::ZERO,
+                GTFArgs::ScriptOutputsCount.into(),
+            ), //REG_OUTPUT_INDEX = the number of outputs in the script
+            //OUTPUT_LOOP_START:
+            op::jnei(REG_OUTPUT_INDEX, RegId::ZERO, JMP_OUTPUT_LOOP_BODY), //jumps to OUTPUT_LOOP_BODY if REG_OUTPUT_INDEX is not 0
+            op::ji(JMP_SWEEP_INPUTS as u32),                               //jumps to SWEEP_INPUTS
+            //OUTPUT_LOOP_BODY:
+            op::subi(REG_OUTPUT_INDEX, REG_OUTPUT_INDEX, 1), //REG_OUTPUT_INDEX = REG_OUTPUT_INDEX - 1
+            op::gtf(
+                REG_OUTPUT_TYPE,
+                REG_OUTPUT_INDEX,
+                GTFArgs::OutputType.into(),
+            ), //REG_OUTPUT_TYPE = the type of output[OUTPUT_INDEX]
+            op::movi(REG_TEMP, OUTPUT_CHANGE_TYPE),          //REG_TEMP = OUTPUT_CHANGE_TYPE
+            op::eq(REG_RESULT, REG_OUTPUT_TYPE, REG_TEMP), //REG_RESULT = if REG_OUTPUT_TYPE equals REG_TEMP
+            op::jnzi(REG_RESULT, JMP_CHECK_TO as u32), //jumps to CHECK_TO if REG_RESULT is not 0
+            op::jnei(REG_OUTPUT_TYPE, RegId::ZERO, JMP_PREDICATE_FAILURE), //jumps to PREDICATE_FAILURE if REG_OUTPUT_TYPE is not OUTPUT_COIN_TYPE (0)
+            //CHECK_TO:
+            op::gtf(REG_TO_PTR, REG_OUTPUT_INDEX, GTFArgs::OutputCoinTo.into()), //REG_TO_PTR = memory location of the recipient of output[OUTPUT_INDEX]
+            op::meq(REG_RESULT, REG_TO_PTR, REG_RECOVERY_PTR, REG_VAL_32), //REG_RESULT = if the 32bytes at REG_TO_PTR equals the 32bytes at REG_RECOVERY_PTR
+            op::jnei(REG_RESULT, RegId::ONE, JMP_PREDICATE_FAILURE), //jumps to PREDICATE_FAILURE if REG_RESULT is not 1
+            op::ji(JMP_OUTPUT_LOOP_START as u32), //jumps back to OUTPUT_LOOP_START
+            //SWEEP_INPUTS:
+            //confirm that no messages with data are included
+            op::gtf(
+                REG_INPUT_INDEX,
+                RegId::ZERO,
+                GTFArgs::ScriptInputsCount.into(),
+            ), //REG_INPUT_INDEX = the number of inputs in the script
+            op::movi(REG_EXPECTED_INPUT_TYPE, INPUT_MESSAGE_TYPE), //REG_EXPECTED_INPUT_TYPE = REG_INPUT_MESSAGE_TYPE
+            //SWEEP_LOOP_START:
+            op::jnei(REG_INPUT_INDEX, RegId::ZERO, JMP_SWEEP_LOOP_BODY), //jumps to SWEEP_LOOP_BODY if REG_INPUT_INDEX is not 0
+            op::ret(RegId::ONE),
+            //SWEEP_LOOP_BODY:
+            op::subi(REG_INPUT_INDEX, REG_INPUT_INDEX, 1), //REG_INPUT_INDEX = REG_INPUT_INDEX - 1
+            op::gtf(REG_INPUT_TYPE, REG_INPUT_INDEX, GTFArgs::InputType.into()), //REG_INPUT_TYPE = the type of input for input[INPUT_INDEX]
+            op::jnei(
+                REG_INPUT_TYPE,
+                REG_EXPECTED_INPUT_TYPE,
+                JMP_SWEEP_LOOP_START,
+            ), //jumps back to SWEEP_LOOP_START if REG_INPUT_TYPE does not equal REG_EXPECTED_INPUT_TYPE
+            op::gtf(
+                REG_INPUT_MSG_DATA_LEN,
+                REG_INPUT_INDEX,
+                GTFArgs::InputMessageDataLength.into(),
+            ), //REG_INPUT_MSG_DATA_LEN = the data length of input[INPUT_INDEX]
+            op::jnei(REG_INPUT_MSG_DATA_LEN, RegId::ZERO, JMP_PREDICATE_FAILURE), //jumps to PREDICATE_FAILURE if REG_INPUT_MSG_DATA_LEN does not equal 0
+            op::ji(JMP_SWEEP_LOOP_START as u32), //jumps back to SWEEP_LOOP_START
+            //CHECK_INPUTS:
+            //confirm that no other messages with data are included
+            op::gtf(
+                REG_INPUT_INDEX,
+                RegId::ZERO,
+                GTFArgs::ScriptInputsCount.into(),
+            ), //REG_INPUT_INDEX = the number of inputs in the script
+        ],
+        data_messages_check(JMP_LOOP_START, JMP_SKIP_DATA_CHECK, JMP_PREDICATE_FAILURE),
+        vec![
+            op::ret(RegId::ONE),
+            //PREDICATE_FAILURE:
+            op::ret(RegId::ZERO),
+            //referenced data (expected relay script hash, expected sweep script hash, recovery address)
+            //00000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000
+            //00000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000
+            //00000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000
+        ],
+    ]
+    .concat()
+    .into_iter()
+    .flat_map(Instruction::to_bytes)
+    .collect();
+
+    //add referenced data (expected relay script hash, expected sweep script hash, recovery address)
+    predicate.append(&mut relay_script_hash.to_vec());
+    predicate.append(&mut sweep_script_hash.to_vec());
+    predicate.append(&mut recovery_address.to_vec());
+    predicate
+}
+
+// Gets the instructions that hash the transaction script into the 32 bytes at REG_HASH_PTR and
+// compare it with the expected script hash at the start of the referenced data, jumping to
+// `jmp_mismatch` if they differ. REG_VAL_32 is set to 32 if `set_val_32` is true and must
+// already be 32 otherwise.
+fn script_hash_check(
+    ref_data_start_ptr: u16,
+    set_val_32: bool,
+    jmp_mismatch: u16,
+) -> Vec<Instruction> {
+    let mut instructions = vec![
         //compute script hash
         op::gtf(REG_SCRIPT_PTR, RegId::ZERO, GTFArgs::Script.into()), //REG_SCRIPT_PTR = script data address
         op::gtf(REG_SCRIPT_LEN, RegId::ZERO, GTFArgs::ScriptLength.into()), //REG_SCRIPT_LEN = script data length
         op::s256(REG_HASH_PTR, REG_SCRIPT_PTR, REG_SCRIPT_LEN), //32bytes at SCRIPT_HASH_PTR = hash of the script
         //compare hash with expected
-        op::addi(REG_EXPECTED_HASH_PTR, RegId::IS, REF_DATA_START_PTR), //REG_EXPECTED_HASH_PTR = address of reference data at end of program
-        op::movi(REG_VAL_32, 32),                                       //REG_VAL_32 = 32
+        op::addi(REG_EXPECTED_HASH_PTR, RegId::IS, ref_data_start_ptr), //REG_EXPECTED_HASH_PTR = address of reference data at end of program
+    ];
+    if set_val_32 {
+        instructions.push(op::movi(REG_VAL_32, 32)); //REG_VAL_32 = 32
+    }
+    instructions.extend([
         op::meq(REG_RESULT, REG_EXPECTED_HASH_PTR, REG_HASH_PTR, REG_VAL_32), //REG_RESULT = if the 32bytes at REG_HASH_PTR equals the 32bytes at REG_EXPECTED_HASH_PTR
-        op::jnei(REG_RESULT, RegId::ONE, JMP_PREDICATE_FAILURE), //jumps to PREDICATE_FAILURE if REG_RESULT is not 1
-        //confirm that no other messages with data are included
-        op::gtf(
-            REG_INPUT_INDEX,
-            RegId::ZERO,
-            GTFArgs::ScriptInputsCount.into(),
-        ), //REG_INPUT_INDEX = the number of inputs in the script
+        op::jnei(REG_RESULT, RegId::ONE, jmp_mismatch), //jumps to jmp_mismatch if REG_RESULT is not 1
+    ]);
+    instructions
+}
+
+// Gets the instructions that loop over the inputs from the count in REG_INPUT_INDEX down to
+// input[1], jumping to `jmp_failure` if any of them is a message with data. The loop starts one
+// instruction in, at `jmp_loop_start`, and its last instruction is at `jmp_skip_data_check`.
+fn data_messages_check(
+    jmp_loop_start: u16,
+    jmp_skip_data_check: u16,
+    jmp_failure: u16,
+) -> Vec<Instruction> {
+    vec![
         op::movi(REG_EXPECTED_INPUT_TYPE, INPUT_MESSAGE_TYPE), //REG_EXPECTED_INPUT_TYPE = REG_INPUT_MESSAGE_TYPE
         //LOOP_START:
         op::subi(REG_INPUT_INDEX, REG_INPUT_INDEX, 1), //REG_INPUT_INDEX = REG_INPUT_INDEX - 1
         //check if the input is a message input
         op::gtf(REG_INPUT_TYPE, REG_INPUT_INDEX, GTFArgs::InputType.into()), //REG_INPUT_TYPE = the type of input for input[INPUT_INDEX]
-        op::jnei(REG_INPUT_TYPE, REG_EXPECTED_INPUT_TYPE, JMP_SKIP_DATA_CHECK), //skips to SKIP_DATA_CHECK if REG_INPUT_TYPE does not equal REG_EXPECTED_INPUT_TYPE
+        op::jnei(REG_INPUT_TYPE, REG_EXPECTED_INPUT_TYPE, jmp_skip_data_check), //skips to SKIP_DATA_CHECK if REG_INPUT_TYPE does not equal REG_EXPECTED_INPUT_TYPE
         //check if the input message has data
         op::gtf(
             REG_INPUT_MSG_DATA_LEN,
             REG_INPUT_INDEX,
             GTFArgs::InputMessageDataLength.into(),
         ), //REG_INPUT_MSG_DATA_LEN = the data length of input[INPUT_INDEX]
-        op::jnei(REG_INPUT_MSG_DATA_LEN, RegId::ZERO, JMP_PREDICATE_FAILURE), //jumps to PREDICATE_FAILURE if REG_INPUT_MSG_DATA_LEN does not equal 0
+        op::jnei(REG_INPUT_MSG_DATA_LEN, RegId::ZERO, jmp_failure), //jumps to jmp_failure if REG_INPUT_MSG_DATA_LEN does not equal 0
         //SKIP_DATA_CHECK:
-        op::jnei(REG_INPUT_INDEX, RegId::ONE, JMP_LOOP_START), //jumps back to LOOP_START if REG_INPUT_INDEX does not equal 1
-        op::ret(RegId::ONE),
-        //PREDICATE_FAILURE:
-        op::ret(RegId::ZERO),
-        //referenced data (expected script hash)
-        //00000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000
+        op::jnei(REG_INPUT_INDEX, RegId::ONE, jmp_loop_start), //jumps back to LOOP_START if REG_INPUT_INDEX does not equal 1
     ]
-    .into_iter()
-    .collect();
+}
 
-    //add referenced data (expected script hash)
-    predicate.append(&mut crate::script_hash().to_vec());
-    predicate
+// Gets the instructions that load the message data of input[0] into REG_MSG_DATA_PTR (0x1b),
+// jumping to `jmp_failure` if it is shorter than `min_len` bytes
+fn message_data_len_check(min_len: u32, jmp_failure: u16) -> Vec<Instruction> {
+    //register names
+    const REG_MSG_DATA_PTR: u8 = 0x1b;
+    const REG_MIN_DATA_LEN: u8 = 0x1c;
+
+    vec![
+        op::gtf(
+            REG_MSG_DATA_PTR,
+            RegId::ZERO,
+            GTFArgs::InputMessageData.into(),
+        ), //REG_MSG_DATA_PTR = memory location of the message data from input[0]
+        op::gtf(
+            REG_INPUT_MSG_DATA_LEN,
+            RegId::ZERO,
+            GTFArgs::InputMessageDataLength.into(),
+        ), //REG_INPUT_MSG_DATA_LEN = the data length of input[0]
+        op::movi(REG_MIN_DATA_LEN, min_len), //REG_MIN_DATA_LEN = min_len
+        op::lt(REG_RESULT, REG_INPUT_MSG_DATA_LEN, REG_MIN_DATA_LEN), //REG_RESULT = if REG_INPUT_MSG_DATA_LEN is less than REG_MIN_DATA_LEN
+        op::jnei(REG_RESULT, RegId::ZERO, jmp_failure), //jumps to jmp_failure if REG_RESULT is not 0
+    ]
+}
+
+// Gets the instructions that check the predicate data of input[0] is the index of a witness
+// holding a signature over the transaction ID by the relayer at bytes 32..64 of the message data
+// in REG_MSG_DATA_PTR (0x1b), jumping to `jmp_failure` otherwise. REG_VAL_32 is set to 32 if
+// `set_val_32` is true and must already be 32 otherwise.
+fn relayer_signature_check(set_val_32: bool, jmp_failure: u16) -> Vec<Instruction> {
+    //register names
+    const REG_MSG_DATA_PTR: u8 = 0x1b;
+    const REG_PREDICATE_DATA_PTR: u8 = 0x1d;
+    const REG_PREDICATE_DATA_LEN: u8 = 0x1e;
+    const REG_VAL_8: u8 = 0x1f;
+    const REG_WITNESS_INDEX: u8 = 0x20;
+    const REG_SIGNATURE_PTR: u8 = 0x21;
+    const REG_SIGNATURE_LEN: u8 = 0x22;
+    const REG_VAL_64: u8 = 0x23;
+    const REG_PUBLIC_KEY_PTR: u8 = 0x24;
+    const REG_RELAYER_PTR: u8 = 0x25;
+
+    let mut instructions = vec![
+        //read the signature witness index from the predicate data
+        op::gtf(
+            REG_PREDICATE_DATA_PTR,
+            RegId::ZERO,
+            GTFArgs::InputMessagePredicateData.into(),
+        ), //REG_PREDICATE_DATA_PTR = memory location of the predicate data from input[0]
+        op::gtf(
+            REG_PREDICATE_DATA_LEN,
+            RegId::ZERO,
+            GTFArgs::InputMessagePredicateDataLength.into(),
+        ), //REG_PREDICATE_DATA_LEN = the predicate data length of input[0]
+        op::movi(REG_VAL_8, 8), //REG_VAL_8 = 8
+        op::jnei(REG_PREDICATE_DATA_LEN, REG_VAL_8, jmp_failure), //jumps to jmp_failure if REG_PREDICATE_DATA_LEN does not equal 8
+        op::lw(REG_WITNESS_INDEX, REG_PREDICATE_DATA_PTR, 0), //REG_WITNESS_INDEX = word at REG_PREDICATE_DATA_PTR
+        op::gtf(
+            REG_SIGNATURE_PTR,
+            REG_WITNESS_INDEX,
+            GTFArgs::WitnessData.into(),
+        ), //REG_SIGNATURE_PTR = memory location of the data of witness[WITNESS_INDEX]
+        op::gtf(
+            REG_SIGNATURE_LEN,
+            REG_WITNESS_INDEX,
+            GTFArgs::WitnessDataLength.into(),
+        ), //REG_SIGNATURE_LEN = the data length of witness[WITNESS_INDEX]
+        op::movi(REG_VAL_64, 64),                             //REG_VAL_64 = 64
+        op::jnei(REG_SIGNATURE_LEN, REG_VAL_64, jmp_failure), //jumps to jmp_failure if REG_SIGNATURE_LEN does not equal 64
+        //recover the signer of the transaction ID
+        op::move_(REG_PUBLIC_KEY_PTR, RegId::SP), //REG_PUBLIC_KEY_PTR = stack pointer
+        op::cfei(64),                             //extends current call frame stack by 64 bytes
+        op::ecr(REG_PUBLIC_KEY_PTR, REG_SIGNATURE_PTR, RegId::ZERO), //64bytes at REG_PUBLIC_KEY_PTR = public key recovered from the signature over the transaction ID at address 0
+        op::jnei(RegId::ERR, RegId::ZERO, jmp_failure), //jumps to jmp_failure if the signature could not be recovered
+        op::s256(REG_PUBLIC_KEY_PTR, REG_PUBLIC_KEY_PTR, REG_VAL_64), //32bytes at REG_PUBLIC_KEY_PTR = hash of the public key [signer address]
+        //compare signer address with the relayer address
+        op::addi(REG_RELAYER_PTR, REG_MSG_DATA_PTR, 32), //REG_RELAYER_PTR = REG_MSG_DATA_PTR + 32bytes [relayer address]
+    ];
+    if set_val_32 {
+        instructions.push(op::movi(REG_VAL_32, 32)); //REG_VAL_32 = 32
+    }
+    instructions.extend([
+        op::meq(REG_RESULT, REG_PUBLIC_KEY_PTR, REG_RELAYER_PTR, REG_VAL_32), //REG_RESULT = if the 32bytes at REG_PUBLIC_KEY_PTR equals the 32bytes at REG_RELAYER_PTR
+        op::jnei(REG_RESULT, RegId::ONE, jmp_failure), //jumps to jmp_failure if REG_RESULT is not 1
+    ]);
+    instructions
 }
 
 #[cfg(test)]
@@ -92,4 +1156,123 @@ mod tests {
         let serialized = hex::encode(&bytecode);
         insta::assert_snapshot!(serialized);
     }
+
+    // Ensure the v2 predicate bytecode doesn't change
+    #[test]
+    fn snapshot_predicate_v2_bytecode() {
+        let bytecode = bytecode_for_script(crate::MessageReceiverVersion::V2.script_hash());
+        let serialized = hex::encode(&bytecode);
+        insta::assert_snapshot!(serialized);
+    }
+
+    // Ensure the refundable predicate bytecode doesn't change
+    #[test]
+    fn snapshot_refundable_predicate_bytecode() {
+        let bytecode =
+            refundable_bytecode_for_scripts(crate::script_hash(), crate::refund_script_hash());
+        let serialized = hex::encode(&bytecode);
+        insta::assert_snapshot!(serialized);
+    }
+
+    // Ensure the time-locked predicate bytecode doesn't change
+    #[test]
+    fn snapshot_time_locked_predicate_bytecode() {
+        let bytecode = time_locked_bytecode_for_script(crate::script_hash());
+        let serialized = hex::encode(&bytecode);
+        insta::assert_snapshot!(serialized);
+    }
+
+    // Ensure the relayer fee predicate bytecode doesn't change
+    #[test]
+    fn snapshot_fee_predicate_bytecode() {
+        let bytecode = bytecode_for_script(crate::fee_script_hash());
+        let serialized = hex::encode(&bytecode);
+        insta::assert_snapshot!(serialized);
+    }
+
+    // Ensure the code root pinned predicate bytecode doesn't change
+    #[test]
+    fn snapshot_pinned_predicate_bytecode() {
+        let bytecode = bytecode_for_script(crate::pinned_script_hash());
+        let serialized = hex::encode(&bytecode);
+        insta::assert_snapshot!(serialized);
+    }
+
+    // Ensure the relay event predicate bytecode doesn't change
+    #[test]
+    fn snapshot_event_predicate_bytecode() {
+        let bytecode = bytecode_for_script(crate::event_script_hash());
+        let serialized = hex::encode(&bytecode);
+        insta::assert_snapshot!(serialized);
+    }
+
+    // Ensure the self-funded predicate bytecode doesn't change
+    #[test]
+    fn snapshot_self_funded_predicate_bytecode() {
+        let bytecode = bytecode_for_script(crate::self_funded_script_hash());
+        let serialized = hex::encode(&bytecode);
+        insta::assert_snapshot!(serialized);
+    }
+
+    // Ensure the allowlist predicate bytecode doesn't change
+    #[test]
+    fn snapshot_allowlist_predicate_bytecode() {
+        let allowlist = crate::allowlist::ContractAllowlist::new(&[[1u8; 32], [2u8; 32]]);
+        let bytecode = allowlist_bytecode_for_script(crate::script_hash(), allowlist.root());
+        let serialized = hex::encode(&bytecode);
+        insta::assert_snapshot!(serialized);
+    }
+
+    // Ensure the strict predicate bytecode doesn't change
+    #[test]
+    fn snapshot_strict_predicate_bytecode() {
+        let bytecode = strict_bytecode_for_script(crate::script_hash(), 3, 1);
+        let serialized = hex::encode(&bytecode);
+        insta::assert_snapshot!(serialized);
+    }
+
+    // Ensure the sealed outputs predicate bytecode doesn't change
+    #[test]
+    fn snapshot_sealed_outputs_predicate_bytecode() {
+        let bytecode = sealed_outputs_bytecode_for_script(crate::script_hash());
+        let serialized = hex::encode(&bytecode);
+        insta::assert_snapshot!(serialized);
+    }
+
+    // Ensure the relayer-authorized predicate bytecode doesn't change
+    #[test]
+    fn snapshot_relayer_predicate_bytecode() {
+        let bytecode = relayer_bytecode_for_script(crate::script_hash());
+        let serialized = hex::encode(&bytecode);
+        insta::assert_snapshot!(serialized);
+    }
+
+    // Ensure the relay window predicate bytecode doesn't change
+    #[test]
+    fn snapshot_relay_window_predicate_bytecode() {
+        let bytecode = relay_window_bytecode_for_script(crate::script_hash());
+        let serialized = hex::encode(&bytecode);
+        insta::assert_snapshot!(serialized);
+    }
+
+    // Ensure the sender allowlist predicate bytecode doesn't change
+    #[test]
+    fn snapshot_sender_allowlist_predicate_bytecode() {
+        let bytecode =
+            sender_allowlist_bytecode_for_script(crate::script_hash(), &[[1u8; 32], [2u8; 32]]);
+        let serialized = hex::encode(&bytecode);
+        insta::assert_snapshot!(serialized);
+    }
+
+    // Ensure the recoverable predicate bytecode doesn't change
+    #[test]
+    fn snapshot_recoverable_predicate_bytecode() {
+        let bytecode = recoverable_bytecode_for_scripts(
+            crate::script_hash(),
+            crate::sweep_script_hash(),
+            [1u8; 32],
+        );
+        let serialized = hex::encode(&bytecode);
+        insta::assert_snapshot!(serialized);
+    }
 }
@@ -0,0 +1,87 @@
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use fuel_tx::ConsensusParameters;
+
+use crate::{predicate_asm, script_asm, script_v2_asm};
+
+// Version of the `MessageReceiver` ABI that a message-to-contract script calls into.
+// Each version has its own script and predicate: messages for v1 receivers keep using
+// the v1 predicate root while messages for v2 receivers are sent to the v2 predicate root.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MessageReceiverVersion {
+    // process_message(msg_idx: u8)
+    V1,
+    // process_message_v2(sender: b256, amount: u64, nonce: b256, data_ptr: u64, data_len: u64)
+    V2,
+}
+
+impl MessageReceiverVersion {
+    // All receiver ABI versions, oldest first
+    pub const ALL: [MessageReceiverVersion; 2] = [Self::V1, Self::V2];
+
+    // Gets the signature of the receiver function called by the script for this version
+    pub fn function_signature(self) -> &'static str {
+        match self {
+            Self::V1 => script_asm::PROCESS_MESSAGE_FUNCTION_SIGNATURE,
+            Self::V2 => script_v2_asm::PROCESS_MESSAGE_V2_FUNCTION_SIGNATURE,
+        }
+    }
+
+    // Gets the bytecode of the message-to-contract script for this version
+    pub fn script_bytecode(self) -> Vec<u8> {
+        match self {
+            Self::V1 => script_asm::bytecode(),
+            Self::V2 => script_v2_asm::bytecode(),
+        }
+    }
+
+    // Gets the hash of the message-to-contract script for this version
+    pub fn script_hash(self) -> [u8; 32] {
+        crate::sha256(&self.script_bytecode())
+    }
+
+    // Gets the bytecode of the message-to-contract predicate for this version
+    pub fn predicate_bytecode(self) -> Vec<u8> {
+        predicate_asm::bytecode_for_script(self.script_hash())
+    }
+
+    // Gets the root of the message-to-contract predicate for this version
+    #[cfg(feature = "std")]
+    pub fn predicate_root(self, cparams: &ConsensusParameters) -> [u8; 32] {
+        crate::root(&self.predicate_bytecode(), cparams)
+    }
+
+    // Gets the receiver ABI version targeted by the given script, if it is a message-to-contract script
+    pub fn of_script(script: &[u8]) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|version| version.script_bytecode() == script)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identifies_script_versions() {
+        for version in MessageReceiverVersion::ALL {
+            let script = version.script_bytecode();
+            assert_eq!(MessageReceiverVersion::of_script(&script), Some(version));
+        }
+        assert_eq!(
+            MessageReceiverVersion::of_script(&[0u8, 1u8, 2u8, 3u8]),
+            None
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn versions_have_distinct_predicates() {
+        let cparams = ConsensusParameters::default();
+        let v1_root = MessageReceiverVersion::V1.predicate_root(&cparams);
+        let v2_root = MessageReceiverVersion::V2.predicate_root(&cparams);
+        assert_ne!(v1_root, v2_root);
+        assert_eq!(v1_root, crate::predicate_root(&cparams));
+    }
+}
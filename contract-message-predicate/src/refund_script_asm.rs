@@ -0,0 +1,282 @@
+use alloc::{vec, vec::Vec};
+use fuel_asm::{op, GTFArgs, Instruction, RegId};
+
+const BYTES_PER_INSTR: u16 = 4;
+const INPUT_COIN_TYPE: u16 = 0;
+const INPUT_MESSAGE_TYPE: u16 = 2;
+const OUTPUT_COIN_TYPE: u16 = 0;
+const TRANSACTION_SCRIPT_TYPE: u16 = 0;
+
+// Byte offsets of the refund fields in the data of a refundable message
+const REFUND_ADDRESS_OFFSET: usize = 32;
+const REFUND_HEIGHT_OFFSET: usize = 32 + 32;
+pub(crate) const REFUND_HEADER_LEN: usize = 32 + 32 + 8;
+
+// Byte offsets of the relay transaction fields in the script data of a refund that proves the
+// relay reverted [chain id, relay transaction as signed]
+const PROOF_TX_TYPE_OFFSET: usize = 8;
+const PROOF_SCRIPT_LEN_OFFSET: usize = 8 + 8 * 4;
+const PROOF_SCRIPT_DATA_LEN_OFFSET: usize = 8 + 8 * 5;
+const PROOF_SCRIPT_OFFSET: usize = 8 + 8 * 9 + 32;
+
+// Byte offset of the nonce in a serialized message input [type, sender, recipient, amount]
+const MESSAGE_NONCE_OFFSET: usize = 8 + 32 + 32 + 8;
+
+// Gets the bytecode for the message refund script
+pub fn bytecode() -> Vec<u8> {
+    //register names
+    const REG_DATA_PTR: u8 = 0x10;
+    const REG_REFUND_HEIGHT: u8 = 0x11;
+    const REG_BLOCK_HEIGHT: u8 = 0x12;
+    const REG_RESULT: u8 = 0x13;
+    const REG_OUTPUT_FIELD: u8 = 0x14;
+    const REG_MSG_AMOUNT: u8 = 0x15;
+    const REG_VAL_32: u8 = 0x16;
+    const REG_BASE_ASSET_PTR: u8 = 0x17;
+    const REG_LENGTH: u8 = 0x18;
+    const REG_MIN_LENGTH: u8 = 0x19;
+    const REG_INPUT_FIELD: u8 = 0x1a;
+    const REG_EXPECTED_PTR: u8 = 0x1b;
+    const REG_PROOF_PTR: u8 = 0x1c;
+    const REG_HASH_PTR: u8 = 0x1d;
+    const REG_TX_FIELD_PTR: u8 = 0x1e;
+    const REG_EXPECTED_TYPE: u8 = 0x1f;
+
+    //instruction jump points
+    const JMP_CHECK_OUTPUT: u16 = 9;
+    const JMP_CHECK_REVERT_PROOF: u16 = 27;
+    const JMP_REFUND_FAILURE: u16 = 69;
+
+    //referenced data start pointer
+    const REF_DATA_START_PTR: u16 = 70 * BYTES_PER_INSTR;
+
+    /* The following assembly code is intended to do the following:
+     *  - Verify that the message data is long enough to hold the refund fields
+     *  - Verify that either the current block height is at least the refund height
+     *   found at bytes 64..72 of the message data, or that a relay of the message
+     *   reverted (see below)
+     *  - Verify that the first output is a coin output of at least the `InputMessage`
+     *   `amount` of base asset to the refund address found at bytes 32..64 of the
+     *   message data
+     *
+     * A reverted relay is proven by spending an output of the relay transaction. The
+     * script data holds the chain ID followed by the relay transaction as it is signed,
+     * and the script verifies the following:
+     *  - The second input is a coin owned by the refund address
+     *  - The hash of the script data is the ID of the transaction that created the coin
+     *  - The relay transaction is a script transaction running the Message to Contract
+     *   script, with an `InputMessage` of the same nonce as its first input
+     * A relay that succeeded would have spent the message, so the relay reverted.
+     *
+     * If these conditions are met, then the script returns and the message amount is
+     * paid out by the coin output. Otherwise the script reverts and the message is not spent.
+     *
+     * note: this code makes the assumption that all memory at VM initialization is set to 0
+     */
+    let mut script: Vec<u8> = vec![
+        //check data length
+        op::gtf(REG_DATA_PTR, RegId::ZERO, GTFArgs::InputMessageData.into()), //REG_DATA_PTR = memory location of the message data from input[0]
+        op::gtf(
+            REG_LENGTH,
+            RegId::ZERO,
+            GTFArgs::InputMessageDataLength.into(),
+        ), //REG_LENGTH = the data length of input[0]
+        op::movi(REG_MIN_LENGTH, REFUND_HEADER_LEN as u32), //REG_MIN_LENGTH = 32 + 32 + 8 [contract id, refund address, refund height]
+        op::lt(REG_RESULT, REG_LENGTH, REG_MIN_LENGTH), //REG_RESULT = if REG_LENGTH is less than REG_MIN_LENGTH
+        op::jnei(REG_RESULT, RegId::ZERO, JMP_REFUND_FAILURE), //jumps to REFUND_FAILURE if REG_RESULT is not 0
+        //check refund height
+        op::lw(
+            REG_REFUND_HEIGHT,
+            REG_DATA_PTR,
+            (REFUND_HEIGHT_OFFSET / 8) as u16,
+        ), //REG_REFUND_HEIGHT = word at REG_DATA_PTR + 64bytes
+        op::bhei(REG_BLOCK_HEIGHT), //REG_BLOCK_HEIGHT = current block height
+        op::lt(REG_RESULT, REG_BLOCK_HEIGHT, REG_REFUND_HEIGHT), //REG_RESULT = if REG_BLOCK_HEIGHT is less than REG_REFUND_HEIGHT
+        op::jnei(REG_RESULT, RegId::ZERO, JMP_CHECK_REVERT_PROOF), //jumps to CHECK_REVERT_PROOF if REG_RESULT is not 0
+        //CHECK_OUTPUT:
+        //check output type
+        op::gtf(REG_OUTPUT_FIELD, RegId::ZERO, GTFArgs::OutputType.into()), //REG_OUTPUT_FIELD = the type of output[0]
+        op::movi(REG_RESULT, OUTPUT_COIN_TYPE as u32), //REG_RESULT = OUTPUT_COIN_TYPE
+        op::jnei(REG_OUTPUT_FIELD, REG_RESULT, JMP_REFUND_FAILURE), //jumps to REFUND_FAILURE if REG_OUTPUT_FIELD does not equal OUTPUT_COIN_TYPE
+        //check output recipient
+        op::gtf(REG_OUTPUT_FIELD, RegId::ZERO, GTFArgs::OutputCoinTo.into()), //REG_OUTPUT_FIELD = memory location of the recipient of output[0]
+        op::addi(REG_DATA_PTR, REG_DATA_PTR, REFUND_ADDRESS_OFFSET as u16), //REG_DATA_PTR = REG_DATA_PTR + 32bytes [refund address]
+        op::movi(REG_VAL_32, 32),                                           //REG_VAL_32 = 32
+        op::meq(REG_RESULT, REG_OUTPUT_FIELD, REG_DATA_PTR, REG_VAL_32), //REG_RESULT = if the 32bytes at REG_OUTPUT_FIELD equals the 32bytes at REG_DATA_PTR
+        op::jnei(REG_RESULT, RegId::ONE, JMP_REFUND_FAILURE), //jumps to REFUND_FAILURE if REG_RESULT is not 1
+        //check output amount
+        op::gtf(
+            REG_MSG_AMOUNT,
+            RegId::ZERO,
+            GTFArgs::InputMessageAmount.into(),
+        ), //REG_MSG_AMOUNT = amount value of message from input[0]
+        op::gtf(
+            REG_OUTPUT_FIELD,
+            RegId::ZERO,
+            GTFArgs::OutputCoinAmount.into(),
+        ), //REG_OUTPUT_FIELD = amount of output[0]
+        op::lt(REG_RESULT, REG_OUTPUT_FIELD, REG_MSG_AMOUNT), //REG_RESULT = if REG_OUTPUT_FIELD is less than REG_MSG_AMOUNT
+        op::jnei(REG_RESULT, RegId::ZERO, JMP_REFUND_FAILURE), //jumps to REFUND_FAILURE if REG_RESULT is not 0
+        //check output asset
+        op::move_(REG_BASE_ASSET_PTR, RegId::SP), //REG_BASE_ASSET_PTR = stack pointer
+        op::cfei(32), //extends current call frame stack by 32 bytes [base asset id]
+        op::gtf(
+            REG_OUTPUT_FIELD,
+            RegId::ZERO,
+            GTFArgs::OutputCoinAssetId.into(),
+        ), //REG_OUTPUT_FIELD = memory location of the asset id of output[0]
+        op::meq(REG_RESULT, REG_OUTPUT_FIELD, REG_BASE_ASSET_PTR, REG_VAL_32), //REG_RESULT = if the 32bytes at REG_OUTPUT_FIELD equals the 32bytes at REG_BASE_ASSET_PTR
+        op::jnei(REG_RESULT, RegId::ONE, JMP_REFUND_FAILURE), //jumps to REFUND_FAILURE if REG_RESULT is not 1
+        op::ret(RegId::ONE),
+        //CHECK_REVERT_PROOF:
+        //check the proof coin is owned by the refund address
+        op::gtf(REG_INPUT_FIELD, RegId::ONE, GTFArgs::InputType.into()), //REG_INPUT_FIELD = the type of input[1]
+        op::movi(REG_EXPECTED_TYPE, INPUT_COIN_TYPE as u32), //REG_EXPECTED_TYPE = INPUT_COIN_TYPE
+        op::jnei(REG_INPUT_FIELD, REG_EXPECTED_TYPE, JMP_REFUND_FAILURE), //jumps to REFUND_FAILURE if REG_INPUT_FIELD does not equal INPUT_COIN_TYPE
+        op::gtf(REG_INPUT_FIELD, RegId::ONE, GTFArgs::InputCoinOwner.into()), //REG_INPUT_FIELD = memory location of the owner of input[1]
+        op::addi(REG_EXPECTED_PTR, REG_DATA_PTR, REFUND_ADDRESS_OFFSET as u16), //REG_EXPECTED_PTR = REG_DATA_PTR + 32bytes [refund address]
+        op::movi(REG_VAL_32, 32),                                               //REG_VAL_32 = 32
+        op::meq(REG_RESULT, REG_INPUT_FIELD, REG_EXPECTED_PTR, REG_VAL_32), //REG_RESULT = if the 32bytes at REG_INPUT_FIELD equals the 32bytes at REG_EXPECTED_PTR
+        op::jnei(REG_RESULT, RegId::ONE, JMP_REFUND_FAILURE), //jumps to REFUND_FAILURE if REG_RESULT is not 1
+        //check the proof is the transaction that created the proof coin
+        op::gtf(REG_PROOF_PTR, RegId::ZERO, GTFArgs::ScriptData.into()), //REG_PROOF_PTR = memory location of the script data
+        op::gtf(REG_LENGTH, RegId::ZERO, GTFArgs::ScriptDataLength.into()), //REG_LENGTH = script data length
+        op::move_(REG_HASH_PTR, RegId::SP), //REG_HASH_PTR = stack pointer
+        op::cfei(32),                       //extends current call frame stack by 32 bytes [hash]
+        op::s256(REG_HASH_PTR, REG_PROOF_PTR, REG_LENGTH), //32 bytes at REG_HASH_PTR = hash of the REG_LENGTH bytes at REG_PROOF_PTR
+        op::gtf(REG_INPUT_FIELD, RegId::ONE, GTFArgs::InputCoinTxId.into()), //REG_INPUT_FIELD = memory location of the transaction id of input[1]
+        op::meq(REG_RESULT, REG_HASH_PTR, REG_INPUT_FIELD, REG_VAL_32), //REG_RESULT = if the 32bytes at REG_HASH_PTR equals the 32bytes at REG_INPUT_FIELD
+        op::jnei(REG_RESULT, RegId::ONE, JMP_REFUND_FAILURE), //jumps to REFUND_FAILURE if REG_RESULT is not 1
+        //check the relay transaction runs the relay script
+        op::lw(
+            REG_INPUT_FIELD,
+            REG_PROOF_PTR,
+            (PROOF_TX_TYPE_OFFSET / 8) as u16,
+        ), //REG_INPUT_FIELD = word at REG_PROOF_PTR + 8bytes [transaction type]
+        op::movi(REG_EXPECTED_TYPE, TRANSACTION_SCRIPT_TYPE as u32), //REG_EXPECTED_TYPE = TRANSACTION_SCRIPT_TYPE
+        op::jnei(REG_INPUT_FIELD, REG_EXPECTED_TYPE, JMP_REFUND_FAILURE), //jumps to REFUND_FAILURE if REG_INPUT_FIELD does not equal TRANSACTION_SCRIPT_TYPE
+        op::lw(
+            REG_LENGTH,
+            REG_PROOF_PTR,
+            (PROOF_SCRIPT_LEN_OFFSET / 8) as u16,
+        ), //REG_LENGTH = word at REG_PROOF_PTR + 40bytes [script length]
+        op::addi(REG_TX_FIELD_PTR, REG_PROOF_PTR, PROOF_SCRIPT_OFFSET as u16), //REG_TX_FIELD_PTR = REG_PROOF_PTR + 112bytes [script]
+        op::s256(REG_HASH_PTR, REG_TX_FIELD_PTR, REG_LENGTH), //32 bytes at REG_HASH_PTR = hash of the REG_LENGTH bytes at REG_TX_FIELD_PTR
+        op::addi(REG_EXPECTED_PTR, RegId::IS, REF_DATA_START_PTR), //REG_EXPECTED_PTR = relay script hash at end of program
+        op::meq(REG_RESULT, REG_HASH_PTR, REG_EXPECTED_PTR, REG_VAL_32), //REG_RESULT = if the 32bytes at REG_HASH_PTR equals the 32bytes at REG_EXPECTED_PTR
+        op::jnei(REG_RESULT, RegId::ONE, JMP_REFUND_FAILURE), //jumps to REFUND_FAILURE if REG_RESULT is not 1
+        //skip the padded script and script data of the relay transaction
+        op::addi(REG_LENGTH, REG_LENGTH, 7), //REG_LENGTH = REG_LENGTH + 7
+        op::divi(REG_LENGTH, REG_LENGTH, 8), //REG_LENGTH = REG_LENGTH / 8
+        op::muli(REG_LENGTH, REG_LENGTH, 8), //REG_LENGTH = REG_LENGTH * 8 [script length padded to a word]
+        op::add(REG_TX_FIELD_PTR, REG_TX_FIELD_PTR, REG_LENGTH), //REG_TX_FIELD_PTR = REG_TX_FIELD_PTR + REG_LENGTH [script data]
+        op::lw(
+            REG_LENGTH,
+            REG_PROOF_PTR,
+            (PROOF_SCRIPT_DATA_LEN_OFFSET / 8) as u16,
+        ), //REG_LENGTH = word at REG_PROOF_PTR + 48bytes [script data length]
+        op::addi(REG_LENGTH, REG_LENGTH, 7),                     //REG_LENGTH = REG_LENGTH + 7
+        op::divi(REG_LENGTH, REG_LENGTH, 8),                     //REG_LENGTH = REG_LENGTH / 8
+        op::muli(REG_LENGTH, REG_LENGTH, 8), //REG_LENGTH = REG_LENGTH * 8 [script data length padded to a word]
+        op::add(REG_TX_FIELD_PTR, REG_TX_FIELD_PTR, REG_LENGTH), //REG_TX_FIELD_PTR = REG_TX_FIELD_PTR + REG_LENGTH [first input]
+        //check the first input of the relay transaction is the message
+        op::lw(REG_INPUT_FIELD, REG_TX_FIELD_PTR, 0), //REG_INPUT_FIELD = word at REG_TX_FIELD_PTR [input type]
+        op::movi(REG_EXPECTED_TYPE, INPUT_MESSAGE_TYPE as u32), //REG_EXPECTED_TYPE = INPUT_MESSAGE_TYPE
+        op::jnei(REG_INPUT_FIELD, REG_EXPECTED_TYPE, JMP_REFUND_FAILURE), //jumps to REFUND_FAILURE if REG_INPUT_FIELD does not equal INPUT_MESSAGE_TYPE
+        op::addi(
+            REG_TX_FIELD_PTR,
+            REG_TX_FIELD_PTR,
+            MESSAGE_NONCE_OFFSET as u16,
+        ), //REG_TX_FIELD_PTR = REG_TX_FIELD_PTR + 80bytes [message nonce]
+        op::gtf(
+            REG_INPUT_FIELD,
+            RegId::ZERO,
+            GTFArgs::InputMessageNonce.into(),
+        ), //REG_INPUT_FIELD = memory location of the nonce of input[0]
+        op::meq(REG_RESULT, REG_TX_FIELD_PTR, REG_INPUT_FIELD, REG_VAL_32), //REG_RESULT = if the 32bytes at REG_TX_FIELD_PTR equals the 32bytes at REG_INPUT_FIELD
+        op::jnei(REG_RESULT, RegId::ONE, JMP_REFUND_FAILURE), //jumps to REFUND_FAILURE if REG_RESULT is not 1
+        op::ji(JMP_CHECK_OUTPUT as u32),                      //jumps to CHECK_OUTPUT
+        //REFUND_FAILURE:
+        op::rvrt(RegId::ZERO),
+        //referenced data (relay script hash)
+        //00000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000
+    ]
+    .into_iter()
+    .flat_map(Instruction::to_bytes)
+    .collect();
+
+    //add referenced data (relay script hash)
+    script.extend_from_slice(&crate::script_hash());
+    script
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Ensure the refund script bytecode doesn't change
+    #[test]
+    fn snapshot_refund_script_bytecode() {
+        let bytecode = bytecode();
+        let serialized = hex::encode(&bytecode);
+        insta::assert_snapshot!(serialized);
+    }
+
+    /// Ensure the proof script data of a refund hashes to the ID of the relay it proves
+    #[cfg(feature = "std")]
+    #[test]
+    fn refund_proof_script_data_hashes_to_relay_id() {
+        use fuel_tx::{ConsensusParameters, Input, Output, Transaction, UniqueIdentifier};
+
+        let cparams = ConsensusParameters {
+            chain_id: 7,
+            ..ConsensusParameters::DEFAULT
+        };
+        let message = Input::message_data_predicate(
+            [1u8; 32].into(),
+            crate::refundable_predicate_root(&cparams).into(),
+            100,
+            [2u8; 32].into(),
+            crate::refundable_message_data(&[3u8; 32], &[4u8; 32], 10, &[5u8; 8]),
+            crate::refundable_predicate_bytecode(),
+            vec![],
+        );
+        let coin = Input::coin_signed(
+            Default::default(),
+            [4u8; 32].into(),
+            50,
+            Default::default(),
+            fuel_tx::TxPointer::new(9.into(), 1),
+            0,
+            Default::default(),
+        );
+        let contract = Input::contract(
+            Default::default(),
+            [6u8; 32].into(),
+            [7u8; 32].into(),
+            fuel_tx::TxPointer::new(9.into(), 2),
+            [3u8; 32].into(),
+        );
+        let relay_tx = Transaction::script(
+            1,
+            1_000_000,
+            Default::default(),
+            crate::script_bytecode(),
+            vec![],
+            vec![message, coin, contract],
+            vec![
+                Output::contract(2, [8u8; 32].into(), [9u8; 32].into()),
+                Output::change([4u8; 32].into(), 40, Default::default()),
+                Output::variable([4u8; 32].into(), 10, Default::default()),
+            ],
+            vec![vec![0u8; 64].into()],
+        );
+
+        let data = crate::refund_proof_script_data(&relay_tx, &cparams);
+        assert_eq!(
+            crate::sha256(&data),
+            *relay_tx.id(&cparams),
+            "the proof must hash to the relay ID"
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use fuel_tx::{ContractId, PanicReason, Receipt, ScriptExecutionResult};
+
+use crate::event_script_asm::{RELAY_EVENT_LEN, RELAY_EVENT_TAG};
+
+// Outcome of the `process_message` call made by a relay transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelayOutcome {
+    Success,
+    Revert(u64),
+    Panic(PanicReason),
+}
+
+// Relay of a message to a contract, as logged by the event script. The outcome is read from the
+// result receipts, since a reverting call reverts the script before it can log anything else.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelayEvent {
+    pub message_id: [u8; 32],
+    pub contract_id: [u8; 32],
+    pub amount: u64,
+    pub outcome: RelayOutcome,
+}
+
+impl RelayOutcome {
+    // Gets the outcome of a relay transaction from its receipts. Returns `None` if the transaction
+    // has no script result.
+    pub fn from_receipts(receipts: &[Receipt]) -> Option<Self> {
+        let result = receipts.iter().find_map(|receipt| match receipt {
+            Receipt::ScriptResult { result, .. } => Some(*result),
+            _ => None,
+        })?;
+        match result {
+            ScriptExecutionResult::Success => Some(RelayOutcome::Success),
+            ScriptExecutionResult::Revert => {
+                receipts.iter().rev().find_map(|receipt| match receipt {
+                    Receipt::Revert { ra, .. } => Some(RelayOutcome::Revert(*ra)),
+                    _ => None,
+                })
+            }
+            ScriptExecutionResult::Panic => {
+                receipts.iter().rev().find_map(|receipt| match receipt {
+                    Receipt::Panic { reason, .. } => Some(RelayOutcome::Panic(*reason.reason())),
+                    _ => None,
+                })
+            }
+            ScriptExecutionResult::GenericFailure(_) => None,
+        }
+    }
+}
+
+impl RelayEvent {
+    // Decodes the relay event from the receipts of a relay transaction. Returns `None` if the
+    // transaction didn't run the event script to the relay event or has no script result.
+    pub fn from_receipts(receipts: &[Receipt]) -> Option<Self> {
+        let (message_id, contract_id, amount) =
+            receipts.iter().find_map(|receipt| match receipt {
+                // Note: only logs of the script itself count, contracts can log the same tag
+                Receipt::LogData {
+                    id, ra, rb, data, ..
+                } if *id == ContractId::zeroed()
+                    && *ra == RELAY_EVENT_TAG
+                    && data.len() == RELAY_EVENT_LEN as usize =>
+                {
+                    let message_id: [u8; 32] = data[..32].try_into().unwrap();
+                    let contract_id: [u8; 32] = data[32..].try_into().unwrap();
+                    Some((message_id, contract_id, *rb))
+                }
+                _ => None,
+            })?;
+        let outcome = RelayOutcome::from_receipts(receipts)?;
+        Some(RelayEvent {
+            message_id,
+            contract_id,
+            amount,
+            outcome,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_tx::InstructionResult;
+
+    fn log_receipts(id: ContractId, tag: u64) -> Vec<Receipt> {
+        let data = [[1u8; 32], [2u8; 32]].concat();
+        vec![Receipt::log_data(
+            id,
+            tag,
+            100,
+            0,
+            Default::default(),
+            data,
+            0,
+            0,
+        )]
+    }
+
+    #[test]
+    fn decodes_relay_outcomes() {
+        let mut receipts = log_receipts(ContractId::zeroed(), RELAY_EVENT_TAG);
+        receipts.push(Receipt::script_result(ScriptExecutionResult::Success, 0));
+        assert_eq!(
+            RelayEvent::from_receipts(&receipts),
+            Some(RelayEvent {
+                message_id: [1u8; 32],
+                contract_id: [2u8; 32],
+                amount: 100,
+                outcome: RelayOutcome::Success,
+            })
+        );
+
+        let mut receipts = log_receipts(ContractId::zeroed(), RELAY_EVENT_TAG);
+        receipts.push(Receipt::revert([2u8; 32].into(), 42, 0, 0));
+        receipts.push(Receipt::script_result(ScriptExecutionResult::Revert, 0));
+        let event = RelayEvent::from_receipts(&receipts).unwrap();
+        assert_eq!(event.outcome, RelayOutcome::Revert(42));
+
+        let reason = InstructionResult::error(PanicReason::OutOfGas, 0);
+        let mut receipts = log_receipts(ContractId::zeroed(), RELAY_EVENT_TAG);
+        receipts.push(Receipt::panic([2u8; 32].into(), reason, 0, 0));
+        receipts.push(Receipt::script_result(ScriptExecutionResult::Panic, 0));
+        let event = RelayEvent::from_receipts(&receipts).unwrap();
+        assert_eq!(event.outcome, RelayOutcome::Panic(PanicReason::OutOfGas));
+    }
+
+    #[test]
+    fn ignores_other_logs() {
+        for (id, tag) in [
+            (ContractId::from([2u8; 32]), RELAY_EVENT_TAG),
+            (ContractId::zeroed(), RELAY_EVENT_TAG + 1),
+        ] {
+            let mut receipts = log_receipts(id, tag);
+            receipts.push(Receipt::script_result(ScriptExecutionResult::Success, 0));
+            assert_eq!(RelayEvent::from_receipts(&receipts), None);
+        }
+    }
+}
@@ -1,15 +1,15 @@
-use fuel_asm::{op, GTFArgs, RegId};
-use sha2::{Digest, Sha256};
+use alloc::{vec, vec::Vec};
+use fuel_asm::{op, GTFArgs, Instruction, RegId};
 
-const PROCESS_MESSAGE_FUNCTION_SIGNATURE: &str = "process_message(u8)";
+use crate::selector;
+
+pub(crate) const PROCESS_MESSAGE_FUNCTION_SIGNATURE: &str = "process_message(u8)";
 const BYTES_PER_INSTR: u16 = 4;
 
 // Gets the bytecode for the message-to-contract script
 pub fn bytecode() -> Vec<u8> {
     //calculate function selector
-    let mut fn_sel_hasher = Sha256::new();
-    fn_sel_hasher.update(PROCESS_MESSAGE_FUNCTION_SIGNATURE);
-    let fn_sel_hash: [u8; 32] = fn_sel_hasher.finalize().into();
+    let fn_sel = selector::selector_from_signature(PROCESS_MESSAGE_FUNCTION_SIGNATURE);
 
     //register names
     const REG_MEMORY_START_PTR: u8 = 0x10;
@@ -57,10 +57,11 @@ pub fn bytecode() -> Vec<u8> {
         //00000000
     ]
     .into_iter()
+    .flat_map(Instruction::to_bytes)
     .collect();
 
     //add referenced data (function selector)
-    script.append(&mut fn_sel_hash[0..4].to_vec());
+    script.extend_from_slice(&fn_sel);
     script
 }
 
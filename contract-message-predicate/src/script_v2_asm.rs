@@ -0,0 +1,107 @@
+use alloc::{vec, vec::Vec};
+use fuel_asm::{op, GTFArgs, Instruction, RegId};
+
+use crate::selector;
+
+pub(crate) const PROCESS_MESSAGE_V2_FUNCTION_SIGNATURE: &str =
+    "process_message_v2(b256,u64,b256,u64,u64)";
+const BYTES_PER_INSTR: u16 = 4;
+
+// Gets the bytecode for the message-to-contract script targeting the v2 receiver ABI
+pub fn bytecode() -> Vec<u8> {
+    //calculate function selector
+    let fn_sel = selector::selector_from_signature(PROCESS_MESSAGE_V2_FUNCTION_SIGNATURE);
+
+    //register names
+    const REG_MEMORY_START_PTR: u8 = 0x10;
+    const REG_ASSET_PTR: u8 = REG_MEMORY_START_PTR;
+    const REG_DATA_PTR: u8 = 0x11;
+    const REG_DATA_FN_SEL_PTR: u8 = 0x12;
+    const REG_ARGS_PTR: u8 = 0x13;
+    const REG_MSG_FIELD_PTR: u8 = 0x14;
+    const REG_MSG_AMOUNT: u8 = 0x15;
+    const REG_TEMP: u8 = 0x16;
+
+    //referenced data start pointer
+    const REF_DATA_START_PTR: u16 = 22 * BYTES_PER_INSTR;
+
+    /* The following assembly code is intended to do the following:
+     *  - Call the function `process_message_v2` on the contract with ID that matches
+     *   the first 32 bytes in the message data field, while forwarding the exact
+     *   amount of base asset specified in the `InputMessage` `amount` field
+     *  - Pass the message sender, amount, nonce and the location and length of the
+     *   message data as arguments (a pointer to the arguments struct is the call param)
+     *
+     * note: this code makes the assumption that all memory at VM initialization is set to 0
+     */
+    let mut script: Vec<u8> = vec![
+        //extend stack for contract call data and arguments
+        op::move_(REG_MEMORY_START_PTR, RegId::SP), //REG_MEMORY_START_PTR = stack pointer
+        op::cfei(32 + 32 + 8 + 8 + 88), //extends current call frame stack by 32+32+8+8+88 bytes [base asset id, contract id, param1, param2, args]
+        op::addi(REG_DATA_PTR, REG_MEMORY_START_PTR, 32), //REG_DATA_PTR = REG_MEMORY_START_PTR + 32bytes [memory start pointer + 32]
+        op::addi(REG_DATA_FN_SEL_PTR, REG_DATA_PTR, 32 + 4), //REG_DATA_FN_SEL_PTR = REG_DATA_PTR + 32bytes + 4bytes [call data start pointer + 32 + 4]
+        op::addi(REG_ARGS_PTR, REG_DATA_PTR, 32 + 8 + 8), //REG_ARGS_PTR = REG_DATA_PTR + 48bytes [call data start pointer + 32 + 8 + 8]
+        op::sw(REG_DATA_PTR, REG_ARGS_PTR, 5), //param2 at REG_DATA_PTR + 40bytes = REG_ARGS_PTR
+        //prep call arguments (sender: b256, amount: u64, nonce: b256, data_ptr: u64, data_len: u64)
+        op::gtf(
+            REG_MSG_FIELD_PTR,
+            RegId::ZERO,
+            GTFArgs::InputMessageSender.into(),
+        ), //REG_MSG_FIELD_PTR = memory location of the sender of input[0]
+        op::mcpi(REG_ARGS_PTR, REG_MSG_FIELD_PTR, 32), //32 bytes at REG_ARGS_PTR = the 32 bytes at REG_MSG_FIELD_PTR
+        op::gtf(
+            REG_MSG_AMOUNT,
+            RegId::ZERO,
+            GTFArgs::InputMessageAmount.into(),
+        ), //REG_MSG_AMOUNT = amount value of message from input[0]
+        op::sw(REG_ARGS_PTR, REG_MSG_AMOUNT, 4), //word at REG_ARGS_PTR + 32bytes = REG_MSG_AMOUNT
+        op::gtf(
+            REG_MSG_FIELD_PTR,
+            RegId::ZERO,
+            GTFArgs::InputMessageNonce.into(),
+        ), //REG_MSG_FIELD_PTR = memory location of the nonce of input[0]
+        op::addi(REG_TEMP, REG_ARGS_PTR, 32 + 8), //REG_TEMP = REG_ARGS_PTR + 40bytes
+        op::mcpi(REG_TEMP, REG_MSG_FIELD_PTR, 32), //32 bytes at REG_TEMP = the 32 bytes at REG_MSG_FIELD_PTR
+        op::gtf(
+            REG_MSG_FIELD_PTR,
+            RegId::ZERO,
+            GTFArgs::InputMessageData.into(),
+        ), //REG_MSG_FIELD_PTR = memory location of the message data from input[0]
+        op::sw(REG_ARGS_PTR, REG_MSG_FIELD_PTR, 9), //word at REG_ARGS_PTR + 72bytes = REG_MSG_FIELD_PTR
+        op::gtf(
+            REG_TEMP,
+            RegId::ZERO,
+            GTFArgs::InputMessageDataLength.into(),
+        ), //REG_TEMP = length of the message data from input[0]
+        op::sw(REG_ARGS_PTR, REG_TEMP, 10),         //word at REG_ARGS_PTR + 80bytes = REG_TEMP
+        //prep call parameters
+        op::mcpi(REG_DATA_PTR, REG_MSG_FIELD_PTR, 32), //32 bytes at REG_DATA_PTR = the 32 bytes at REG_MSG_FIELD_PTR (contract id)
+        op::addi(REG_TEMP, RegId::IS, REF_DATA_START_PTR), //REG_TEMP = function selector at end of program
+        op::mcpi(REG_DATA_FN_SEL_PTR, REG_TEMP, 4), //4 bytes at REG_DATA_FN_SEL_PTR = the 4 bytes at REG_TEMP
+        //make contract call
+        op::call(REG_DATA_PTR, REG_MSG_AMOUNT, REG_ASSET_PTR, RegId::CGAS),
+        op::ret(RegId::ZERO),
+        //referenced data (function selector)
+        //00000000
+    ]
+    .into_iter()
+    .flat_map(Instruction::to_bytes)
+    .collect();
+
+    //add referenced data (function selector)
+    script.extend_from_slice(&fn_sel);
+    script
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Ensure the script bytecode doesn't change
+    #[test]
+    fn snapshot_script_v2_bytecode() {
+        let bytecode = bytecode();
+        let serialized = hex::encode(&bytecode);
+        insta::assert_snapshot!(serialized);
+    }
+}
@@ -0,0 +1,189 @@
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use fuel_asm::{Instruction, RegId};
+use sha2::{Digest, Sha256};
+
+const BYTES_PER_INSTR: usize = 4;
+const SELECTOR_LEN: usize = 4;
+
+// Type of a function argument, as it appears in the signature the Sway ABI hashes for selectors
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AbiType {
+    Unit,
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    B256,
+    // str[len]
+    String(usize),
+    // a[type;len]
+    Array(Box<AbiType>, usize),
+    // (types)
+    Tuple(Vec<AbiType>),
+    // s(field types)
+    Struct(Vec<AbiType>),
+    // e(variant types)
+    Enum(Vec<AbiType>),
+}
+
+impl AbiType {
+    // Gets the name of this type as used in function signatures
+    pub fn signature_name(&self) -> String {
+        match self {
+            Self::Unit => "()".to_string(),
+            Self::Bool => "bool".to_string(),
+            Self::U8 => "u8".to_string(),
+            Self::U16 => "u16".to_string(),
+            Self::U32 => "u32".to_string(),
+            Self::U64 => "u64".to_string(),
+            Self::B256 => "b256".to_string(),
+            Self::String(len) => format!("str[{len}]"),
+            Self::Array(element, len) => format!("a[{};{len}]", element.signature_name()),
+            Self::Tuple(elements) => format!("({})", signature_list(elements)),
+            Self::Struct(fields) => format!("s({})", signature_list(fields)),
+            Self::Enum(variants) => format!("e({})", signature_list(variants)),
+        }
+    }
+}
+
+fn signature_list(types: &[AbiType]) -> String {
+    types
+        .iter()
+        .map(AbiType::signature_name)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+// Gets the signature string of a function, e.g. `process_message(u8)`
+pub fn function_signature(name: &str, args: &[AbiType]) -> String {
+    format!("{name}({})", signature_list(args))
+}
+
+// Gets the selector of a function from its signature string
+pub fn selector_from_signature(signature: &str) -> [u8; 4] {
+    let mut fn_sel_hasher = Sha256::new();
+    fn_sel_hasher.update(signature);
+    let fn_sel_hash: [u8; 32] = fn_sel_hasher.finalize().into();
+    let mut selector = [0u8; SELECTOR_LEN];
+    selector.copy_from_slice(&fn_sel_hash[0..SELECTOR_LEN]);
+    selector
+}
+
+// Gets the selector of a function from its name and argument types
+pub fn function_selector(name: &str, args: &[AbiType]) -> [u8; 4] {
+    selector_from_signature(&function_signature(name, args))
+}
+
+// Gets the selector embedded in a message-to-contract script, if any. The selector is found by
+// looking for `ADDI rX $is imm` followed by `MCPI _ rX 4`, which copies the 4 bytes at `imm`
+pub fn script_selector(script: &[u8]) -> Option<[u8; 4]> {
+    let mut selector_offset: Option<(RegId, usize)> = None;
+    for chunk in script.chunks_exact(BYTES_PER_INSTR) {
+        let raw = [chunk[0], chunk[1], chunk[2], chunk[3]];
+        match Instruction::try_from(raw) {
+            Ok(Instruction::ADDI(addi)) => {
+                let (dst, src, imm) = addi.unpack();
+                selector_offset = match src == RegId::IS {
+                    true => Some((dst, u16::from(imm) as usize)),
+                    false => selector_offset.filter(|(reg, _)| *reg != dst),
+                };
+            }
+            Ok(Instruction::MCPI(mcpi)) => {
+                let (_, src, len) = mcpi.unpack();
+                if let Some((reg, offset)) = selector_offset {
+                    if src == reg && u16::from(len) as usize == SELECTOR_LEN {
+                        return script
+                            .get(offset..offset + SELECTOR_LEN)
+                            .map(|bytes| bytes.try_into().unwrap());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MessageReceiverVersion;
+    use alloc::{boxed::Box, vec};
+
+    #[test]
+    fn signatures_match_scripts() {
+        assert_eq!(
+            function_signature("process_message", &[AbiType::U8]),
+            MessageReceiverVersion::V1.function_signature()
+        );
+        assert_eq!(
+            function_signature(
+                "process_message_v2",
+                &[
+                    AbiType::B256,
+                    AbiType::U64,
+                    AbiType::B256,
+                    AbiType::U64,
+                    AbiType::U64
+                ]
+            ),
+            MessageReceiverVersion::V2.function_signature()
+        );
+    }
+
+    #[test]
+    fn signatures_of_compound_types() {
+        let args = [
+            AbiType::Struct(vec![AbiType::B256, AbiType::Bool]),
+            AbiType::Array(Box::new(AbiType::U16), 3),
+            AbiType::Tuple(vec![AbiType::U32, AbiType::String(5)]),
+            AbiType::Enum(vec![AbiType::Unit, AbiType::U64]),
+        ];
+        assert_eq!(
+            function_signature("f", &args),
+            "f(s(b256,bool),a[u16;3],(u32,str[5]),e((),u64))"
+        );
+    }
+
+    #[test]
+    fn finds_selector_in_scripts() {
+        for version in MessageReceiverVersion::ALL {
+            let script = version.script_bytecode();
+            assert_eq!(
+                script_selector(&script),
+                Some(selector_from_signature(version.function_signature()))
+            );
+        }
+        assert_eq!(
+            script_selector(&crate::fee_script_bytecode()),
+            Some(selector_from_signature(
+                MessageReceiverVersion::V1.function_signature()
+            ))
+        );
+        assert_eq!(
+            script_selector(&crate::pinned_script_bytecode()),
+            Some(selector_from_signature(
+                MessageReceiverVersion::V1.function_signature()
+            ))
+        );
+        assert_eq!(
+            script_selector(&crate::event_script_bytecode()),
+            Some(selector_from_signature(
+                MessageReceiverVersion::V1.function_signature()
+            ))
+        );
+        assert_eq!(
+            script_selector(&crate::self_funded_script_bytecode()),
+            Some(selector_from_signature(
+                MessageReceiverVersion::V1.function_signature()
+            ))
+        );
+        assert_eq!(script_selector(&crate::predicate_bytecode()), None);
+    }
+}
@@ -0,0 +1,242 @@
+use alloc::{vec, vec::Vec};
+use fuel_asm::{op, GTFArgs, Instruction, RegId};
+
+use crate::script_asm::PROCESS_MESSAGE_FUNCTION_SIGNATURE;
+use crate::selector;
+
+const BYTES_PER_INSTR: u16 = 4;
+const INPUT_MESSAGE_TYPE: u32 = 2;
+
+// Byte length of the data a self-funded message starts with [contract id, fee cap]
+pub(crate) const SELF_FUNDED_HEADER_LEN: u16 = 32 + 8;
+
+// Revert code of the self-funded script when the max fee exceeds the fee cap or the message amount
+pub const FEE_CAP_EXCEEDED_REVERT_CODE: u64 = 0xfee;
+
+// Cube root of the gas price factor of the consensus parameters, which is too large for `movi`
+const GAS_PRICE_FACTOR_CBRT: u32 = 1_000;
+
+// Gas of the instructions that run after the remaining gas is read [subi, mul, div, jnei, tr, ret],
+// at the default gas costs
+const REIMBURSE_GAS: u16 = 1 + 1 + 1 + 1 + 120 + 61;
+
+// Gets the bytecode for the message-to-contract script that reimburses the transaction fee from
+// the message amount
+pub fn bytecode() -> Vec<u8> {
+    //calculate function selector
+    let fn_sel = selector::selector_from_signature(PROCESS_MESSAGE_FUNCTION_SIGNATURE);
+
+    //register names
+    const REG_MEMORY_START_PTR: u8 = 0x10;
+    const REG_ASSET_PTR: u8 = REG_MEMORY_START_PTR;
+    const REG_DATA_PTR: u8 = 0x11;
+    const REG_DATA_FN_SEL_PTR: u8 = 0x12;
+    const REG_CONTRACT_ADDR_PTR: u8 = 0x13;
+    const REG_FN_SELECTOR_PTR: u8 = 0x14;
+    const REG_MSG_AMOUNT: u8 = 0x15;
+    const REG_LENGTH: u8 = 0x16;
+    const REG_MIN_LENGTH: u8 = 0x17;
+    const REG_RESULT: u8 = 0x18;
+    const REG_FEE_CAP: u8 = 0x19;
+    const REG_FREE_BALANCE: u8 = 0x1a;
+    const REG_FEE: u8 = 0x1b;
+    const REG_REVERT_CODE: u8 = 0x1c;
+    const REG_VAL_32: u8 = 0x1d;
+    const REG_INDEX: u8 = 0x1e;
+    const REG_TYPE: u8 = 0x1f;
+    const REG_EXPECTED_TYPE: u8 = 0x20;
+    const REG_VALUE: u8 = 0x21;
+    const REG_ASSET_ID_PTR: u8 = 0x22;
+    const REG_PAID_IN: u8 = 0x23;
+    const REG_GAS_PRICE: u8 = 0x24;
+    const REG_GAS_PRICE_FACTOR: u8 = 0x25;
+    const REG_REFUND: u8 = 0x26;
+
+    //instruction jump points
+    const JMP_INPUT_LOOP_START: u16 = 17;
+    const JMP_INPUT_LOOP_BODY: u16 = 19;
+    const JMP_CHECK_COIN_INPUT: u16 = 25;
+    const JMP_SUM_OUTPUTS: u16 = 32;
+    const JMP_OUTPUT_LOOP_START: u16 = 33;
+    const JMP_OUTPUT_LOOP_BODY: u16 = 35;
+    const JMP_CHECK_FEE: u16 = 44;
+    const JMP_TRANSFER_REFUND: u16 = 64;
+    const JMP_DATA_FAILURE: u16 = 66;
+    const JMP_FEE_CAP_FAILURE: u16 = 67;
+
+    //referenced data start pointer
+    const REF_DATA_START_PTR: u16 = 69 * BYTES_PER_INSTR;
+
+    /* The following assembly code is intended to do the following:
+     *  - Verify that the first asset ID in memory (bytes 32..64, right after the
+     *   transaction ID) is the base asset ID, and otherwise revert
+     *  - Find the max transaction fee paid in by the relayer: the base asset of all
+     *   message inputs and base asset coin inputs, minus the base asset coin outputs,
+     *   minus the free balance of base asset that the VM places right after its asset
+     *   ID in memory (bytes 64..72) once the max fee has been deducted from the inputs
+     *  - Verify that the max fee is no more than the fee cap found at bytes 32..40 of
+     *   the message data nor the `InputMessage` `amount`, and otherwise revert with the
+     *   fee cap exceeded revert code
+     *  - Call the function `process_message` on the contract with ID that matches
+     *   the first 32 bytes in the message data field, while forwarding the
+     *   `InputMessage` `amount` minus the max fee
+     *  - Transfer the part of the max fee that will be refunded for unused gas, if any,
+     *   to the contract, so that it receives the `InputMessage` `amount` minus the fee
+     *   charged
+     *
+     * The relayer's max fee is returned by the base asset change output: the part not
+     * charged by the VM refund, and the charged part out of the message amount.
+     *
+     * note: this code makes the assumption that all memory at VM initialization is set to 0
+     */
+    let mut script: Vec<u8> = vec![
+        //extend stack for contract call data
+        op::move_(REG_MEMORY_START_PTR, RegId::SP), //REG_MEMORY_START_PTR = stack pointer
+        op::cfei(32 + 32 + 8 + 8), //extends current call frame stack by 32+32+8+8 bytes [base asset id, contract id, param1, param2]
+        op::addi(REG_DATA_PTR, REG_MEMORY_START_PTR, 32), //REG_DATA_PTR = REG_MEMORY_START_PTR + 32bytes [memory start pointer + 32]
+        op::addi(REG_DATA_FN_SEL_PTR, REG_DATA_PTR, 32 + 4), //REG_DATA_FN_SEL_PTR = REG_DATA_PTR + 32bytes + 4bytes [call data start pointer + 32 + 4]
+        //read fee cap
+        op::gtf(
+            REG_MSG_AMOUNT,
+            RegId::ZERO,
+            GTFArgs::InputMessageAmount.into(),
+        ), //REG_MSG_AMOUNT = amount value of message from input[0]
+        op::gtf(
+            REG_CONTRACT_ADDR_PTR,
+            RegId::ZERO,
+            GTFArgs::InputMessageData.into(),
+        ), //REG_CONTRACT_ADDR_PTR = memory location of the message data from input[0]
+        op::gtf(
+            REG_LENGTH,
+            RegId::ZERO,
+            GTFArgs::InputMessageDataLength.into(),
+        ), //REG_LENGTH = the data length of input[0]
+        op::movi(REG_MIN_LENGTH, SELF_FUNDED_HEADER_LEN as u32), //REG_MIN_LENGTH = 32 + 8 [contract id, fee cap]
+        op::lt(REG_RESULT, REG_LENGTH, REG_MIN_LENGTH), //REG_RESULT = if REG_LENGTH is less than REG_MIN_LENGTH
+        op::jnei(REG_RESULT, RegId::ZERO, JMP_DATA_FAILURE), //jumps to DATA_FAILURE if REG_RESULT is not 0
+        op::lw(REG_FEE_CAP, REG_CONTRACT_ADDR_PTR, 4), //REG_FEE_CAP = word at REG_CONTRACT_ADDR_PTR + 32bytes
+        //check the first balance is of the base asset
+        op::movi(REG_VAL_32, 32), //REG_VAL_32 = 32
+        op::meq(REG_RESULT, REG_ASSET_PTR, REG_VAL_32, REG_VAL_32), //REG_RESULT = if the 32bytes at REG_ASSET_PTR [zeroed base asset id] equals the 32bytes at memory address 32bytes [asset id of the first balance]
+        op::jnei(REG_RESULT, RegId::ONE, JMP_DATA_FAILURE), //jumps to DATA_FAILURE if REG_RESULT is not 1
+        //read free balance of base asset
+        op::lw(REG_FREE_BALANCE, RegId::ZERO, 8), //REG_FREE_BALANCE = word at memory address 64bytes [balance of the first asset]
+        //sum the base asset of the inputs
+        op::gtf(REG_INDEX, RegId::ZERO, GTFArgs::ScriptInputsCount.into()), //REG_INDEX = the number of inputs in the script
+        op::movi(REG_EXPECTED_TYPE, INPUT_MESSAGE_TYPE), //REG_EXPECTED_TYPE = INPUT_MESSAGE_TYPE
+        //INPUT_LOOP_START:
+        op::jnei(REG_INDEX, RegId::ZERO, JMP_INPUT_LOOP_BODY), //jumps to INPUT_LOOP_BODY if REG_INDEX is not 0
+        op::ji(JMP_SUM_OUTPUTS as u32),                        //jumps to SUM_OUTPUTS
+        //INPUT_LOOP_BODY:
+        op::subi(REG_INDEX, REG_INDEX, 1), //REG_INDEX = REG_INDEX - 1
+        op::gtf(REG_TYPE, REG_INDEX, GTFArgs::InputType.into()), //REG_TYPE = the type of input[INDEX]
+        op::jnei(REG_TYPE, REG_EXPECTED_TYPE, JMP_CHECK_COIN_INPUT), //jumps to CHECK_COIN_INPUT if REG_TYPE does not equal REG_EXPECTED_TYPE
+        op::gtf(REG_VALUE, REG_INDEX, GTFArgs::InputMessageAmount.into()), //REG_VALUE = amount value of message from input[INDEX]
+        op::add(REG_PAID_IN, REG_PAID_IN, REG_VALUE), //REG_PAID_IN = REG_PAID_IN + REG_VALUE
+        op::ji(JMP_INPUT_LOOP_START as u32),          //jumps back to INPUT_LOOP_START
+        //CHECK_COIN_INPUT:
+        op::jnei(REG_TYPE, RegId::ZERO, JMP_INPUT_LOOP_START), //jumps back to INPUT_LOOP_START if REG_TYPE is not INPUT_COIN_TYPE (0)
+        op::gtf(
+            REG_ASSET_ID_PTR,
+            REG_INDEX,
+            GTFArgs::InputCoinAssetId.into(),
+        ), //REG_ASSET_ID_PTR = memory location of the asset id of input[INDEX]
+        op::meq(REG_RESULT, REG_ASSET_ID_PTR, REG_ASSET_PTR, REG_VAL_32), //REG_RESULT = if the 32bytes at REG_ASSET_ID_PTR equals the 32bytes at REG_ASSET_PTR [zeroed base asset id]
+        op::jnei(REG_RESULT, RegId::ONE, JMP_INPUT_LOOP_START), //jumps back to INPUT_LOOP_START if REG_RESULT is not 1
+        op::gtf(REG_VALUE, REG_INDEX, GTFArgs::InputCoinAmount.into()), //REG_VALUE = amount of input[INDEX]
+        op::add(REG_PAID_IN, REG_PAID_IN, REG_VALUE), //REG_PAID_IN = REG_PAID_IN + REG_VALUE
+        op::ji(JMP_INPUT_LOOP_START as u32),          //jumps back to INPUT_LOOP_START
+        //SUM_OUTPUTS:
+        //subtract the base asset of the coin outputs
+        op::gtf(REG_INDEX, RegId::ZERO, GTFArgs::ScriptOutputsCount.into()), //REG_INDEX = the number of outputs in the script
+        //OUTPUT_LOOP_START:
+        op::jnei(REG_INDEX, RegId::ZERO, JMP_OUTPUT_LOOP_BODY), //jumps to OUTPUT_LOOP_BODY if REG_INDEX is not 0
+        op::ji(JMP_CHECK_FEE as u32),                           //jumps to CHECK_FEE
+        //OUTPUT_LOOP_BODY:
+        op::subi(REG_INDEX, REG_INDEX, 1), //REG_INDEX = REG_INDEX - 1
+        op::gtf(REG_TYPE, REG_INDEX, GTFArgs::OutputType.into()), //REG_TYPE = the type of output[INDEX]
+        op::jnei(REG_TYPE, RegId::ZERO, JMP_OUTPUT_LOOP_START), //jumps back to OUTPUT_LOOP_START if REG_TYPE is not OUTPUT_COIN_TYPE (0)
+        op::gtf(
+            REG_ASSET_ID_PTR,
+            REG_INDEX,
+            GTFArgs::OutputCoinAssetId.into(),
+        ), //REG_ASSET_ID_PTR = memory location of the asset id of output[INDEX]
+        op::meq(REG_RESULT, REG_ASSET_ID_PTR, REG_ASSET_PTR, REG_VAL_32), //REG_RESULT = if the 32bytes at REG_ASSET_ID_PTR equals the 32bytes at REG_ASSET_PTR [zeroed base asset id]
+        op::jnei(REG_RESULT, RegId::ONE, JMP_OUTPUT_LOOP_START), //jumps back to OUTPUT_LOOP_START if REG_RESULT is not 1
+        op::gtf(REG_VALUE, REG_INDEX, GTFArgs::OutputCoinAmount.into()), //REG_VALUE = amount of output[INDEX]
+        op::sub(REG_PAID_IN, REG_PAID_IN, REG_VALUE), //REG_PAID_IN = REG_PAID_IN - REG_VALUE
+        op::ji(JMP_OUTPUT_LOOP_START as u32),         //jumps back to OUTPUT_LOOP_START
+        //CHECK_FEE:
+        op::sub(REG_FEE, REG_PAID_IN, REG_FREE_BALANCE), //REG_FEE = REG_PAID_IN - REG_FREE_BALANCE [max fee]
+        op::lt(REG_RESULT, REG_FEE_CAP, REG_FEE), //REG_RESULT = if REG_FEE_CAP is less than REG_FEE
+        op::jnei(REG_RESULT, RegId::ZERO, JMP_FEE_CAP_FAILURE), //jumps to FEE_CAP_FAILURE if REG_RESULT is not 0
+        op::lt(REG_RESULT, REG_MSG_AMOUNT, REG_FEE), //REG_RESULT = if REG_MSG_AMOUNT is less than REG_FEE
+        op::jnei(REG_RESULT, RegId::ZERO, JMP_FEE_CAP_FAILURE), //jumps to FEE_CAP_FAILURE if REG_RESULT is not 0
+        op::sub(REG_MSG_AMOUNT, REG_MSG_AMOUNT, REG_FEE), //REG_MSG_AMOUNT = REG_MSG_AMOUNT - REG_FEE
+        //prep call parameters
+        op::addi(REG_FN_SELECTOR_PTR, RegId::IS, REF_DATA_START_PTR), //REG_FN_SELECTOR_PTR = function selector at end of program
+        op::mcpi(REG_DATA_PTR, REG_CONTRACT_ADDR_PTR, 32), //32 bytes at REG_DATA_PTR = the 32 bytes at REG_CONTRACT_ADDR_PTR
+        op::mcpi(REG_DATA_FN_SEL_PTR, REG_FN_SELECTOR_PTR, 4), //4 bytes at REG_DATA_FN_SEL_PTR = the 4 bytes at REG_FN_SELECTOR_PTR
+        //make contract call
+        op::call(REG_DATA_PTR, REG_MSG_AMOUNT, REG_ASSET_PTR, RegId::CGAS),
+        //reimburse the contract the part of the max fee refunded for unused gas
+        op::gtf(REG_GAS_PRICE, RegId::ZERO, GTFArgs::ScriptGasPrice.into()), //REG_GAS_PRICE = gas price of the transaction
+        op::movi(REG_GAS_PRICE_FACTOR, GAS_PRICE_FACTOR_CBRT), //REG_GAS_PRICE_FACTOR = GAS_PRICE_FACTOR_CBRT
+        op::muli(
+            REG_GAS_PRICE_FACTOR,
+            REG_GAS_PRICE_FACTOR,
+            GAS_PRICE_FACTOR_CBRT as u16,
+        ), //REG_GAS_PRICE_FACTOR = REG_GAS_PRICE_FACTOR * GAS_PRICE_FACTOR_CBRT
+        op::muli(
+            REG_GAS_PRICE_FACTOR,
+            REG_GAS_PRICE_FACTOR,
+            GAS_PRICE_FACTOR_CBRT as u16,
+        ), //REG_GAS_PRICE_FACTOR = REG_GAS_PRICE_FACTOR * GAS_PRICE_FACTOR_CBRT [gas price factor]
+        op::move_(REG_REFUND, RegId::GGAS),                    //REG_REFUND = remaining gas
+        op::subi(REG_REFUND, REG_REFUND, REIMBURSE_GAS), //REG_REFUND = REG_REFUND - REIMBURSE_GAS [gas remaining after the script returns]
+        op::mul(REG_REFUND, REG_REFUND, REG_GAS_PRICE),  //REG_REFUND = REG_REFUND * REG_GAS_PRICE
+        op::div(REG_REFUND, REG_REFUND, REG_GAS_PRICE_FACTOR), //REG_REFUND = REG_REFUND / REG_GAS_PRICE_FACTOR [refund of unused gas]
+        op::jnei(REG_REFUND, RegId::ZERO, JMP_TRANSFER_REFUND), //jumps to TRANSFER_REFUND if REG_REFUND is not 0
+        op::ret(RegId::ZERO),
+        //TRANSFER_REFUND:
+        op::tr(REG_DATA_PTR, REG_REFUND, REG_ASSET_PTR), //transfers REG_REFUND of base asset to the contract at REG_DATA_PTR
+        op::ret(RegId::ZERO),
+        //DATA_FAILURE:
+        op::rvrt(RegId::ZERO),
+        //FEE_CAP_FAILURE:
+        op::movi(REG_REVERT_CODE, FEE_CAP_EXCEEDED_REVERT_CODE as u32), //REG_REVERT_CODE = fee cap exceeded revert code
+        op::rvrt(REG_REVERT_CODE),
+        //referenced data (function selector)
+        //00000000
+    ]
+    .into_iter()
+    .flat_map(Instruction::to_bytes)
+    .collect();
+
+    //add referenced data (function selector)
+    script.extend_from_slice(&fn_sel);
+    script
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Ensure the self-funded script bytecode doesn't change
+    #[test]
+    fn snapshot_self_funded_script_bytecode() {
+        let bytecode = bytecode();
+        let serialized = hex::encode(&bytecode);
+        insta::assert_snapshot!(serialized);
+    }
+
+    // Ensure the gas price factor built by the script is the one of the consensus parameters
+    #[cfg(feature = "std")]
+    #[test]
+    fn gas_price_factor_matches_consensus_parameters() {
+        assert_eq!(
+            (GAS_PRICE_FACTOR_CBRT as u64).pow(3),
+            fuel_tx::ConsensusParameters::DEFAULT.gas_price_factor
+        );
+    }
+}
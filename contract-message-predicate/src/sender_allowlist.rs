@@ -0,0 +1,78 @@
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use fuel_tx::ConsensusParameters;
+
+use crate::predicate_asm;
+
+// Set of L1 addresses that may send messages to a sender allowlist predicate, such as the L1
+// bridge portal. The predicate embeds the senders and rejects messages whose sender is not one
+// of them. An empty allowlist results in a predicate that rejects every message.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SenderAllowlist {
+    senders: Vec<[u8; 32]>,
+}
+
+impl SenderAllowlist {
+    // Creates an empty allowlist, to be configured with `with_sender`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Adds a trusted sender to the allowlist (senders already in the allowlist are ignored)
+    pub fn with_sender(mut self, sender: [u8; 32]) -> Self {
+        if !self.senders.contains(&sender) {
+            self.senders.push(sender);
+        }
+        self
+    }
+
+    // Adds each of the given trusted senders to the allowlist
+    pub fn with_senders(self, senders: &[[u8; 32]]) -> Self {
+        senders
+            .iter()
+            .fold(self, |allowlist, sender| allowlist.with_sender(*sender))
+    }
+
+    // Gets the trusted senders, in the order they were added
+    pub fn senders(&self) -> &[[u8; 32]] {
+        &self.senders
+    }
+
+    // Gets the bytecode of the sender allowlist predicate for this allowlist
+    pub fn predicate_bytecode(&self) -> Vec<u8> {
+        predicate_asm::sender_allowlist_bytecode_for_script(crate::script_hash(), &self.senders)
+    }
+
+    // Gets the root of the sender allowlist predicate for this allowlist
+    #[cfg(feature = "std")]
+    pub fn predicate_root(&self, cparams: &ConsensusParameters) -> [u8; 32] {
+        crate::root(&self.predicate_bytecode(), cparams)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_allowlist_of_distinct_senders() {
+        let allowlist = SenderAllowlist::new()
+            .with_sender([1u8; 32])
+            .with_senders(&[[2u8; 32], [1u8; 32]]);
+        assert_eq!(allowlist.senders(), &[[1u8; 32], [2u8; 32]]);
+
+        // Note: the senders are appended to the predicate after the script hash
+        let predicate = allowlist.predicate_bytecode();
+        assert_eq!(
+            predicate[predicate.len() - 64..predicate.len() - 32],
+            [1u8; 32]
+        );
+        assert_eq!(predicate[predicate.len() - 32..], [2u8; 32]);
+        assert_ne!(
+            predicate,
+            SenderAllowlist::new()
+                .with_sender([1u8; 32])
+                .predicate_bytecode()
+        );
+    }
+}
@@ -0,0 +1,31 @@
+use alloc::{vec, vec::Vec};
+use fuel_asm::{op, Instruction, RegId};
+
+// Gets the bytecode for the script that sweeps coins owned by the recoverable predicate
+pub fn bytecode() -> Vec<u8> {
+    /* The following assembly code is intended to do the following:
+     *  - Return without doing anything, the swept coins move to the recovery address
+     *   through the transaction outputs, which are checked by the recoverable predicate
+     */
+    let script: Vec<u8> = vec![
+        op::ret(RegId::ONE), //return 1
+    ]
+    .into_iter()
+    .flat_map(Instruction::to_bytes)
+    .collect();
+
+    script
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Ensure the sweep script bytecode doesn't change
+    #[test]
+    fn snapshot_sweep_script_bytecode() {
+        let bytecode = bytecode();
+        let serialized = hex::encode(&bytecode);
+        insta::assert_snapshot!(serialized);
+    }
+}
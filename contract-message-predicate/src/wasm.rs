@@ -0,0 +1,38 @@
+use alloc::vec::Vec;
+use wasm_bindgen::prelude::*;
+
+// JavaScript bindings for computing deposit addresses and message data client-side.
+// Byte arrays are passed and returned as `Uint8Array`.
+
+// Gets the bytecode of the message-to-contract predicate
+#[wasm_bindgen(js_name = predicateBytecode)]
+pub fn predicate_bytecode() -> Vec<u8> {
+    crate::predicate_bytecode()
+}
+
+// Gets the bytecode of the message-to-contract script
+#[wasm_bindgen(js_name = scriptBytecode)]
+pub fn script_bytecode() -> Vec<u8> {
+    crate::script_bytecode()
+}
+
+// Gets the hash of the message-to-contract script
+#[wasm_bindgen(js_name = scriptHash)]
+pub fn script_hash() -> Vec<u8> {
+    crate::script_hash().to_vec()
+}
+
+// Gets the root of the message-to-contract predicate on the chain with the given ID
+#[wasm_bindgen(js_name = predicateRoot)]
+pub fn predicate_root(chain_id: u64) -> Vec<u8> {
+    crate::predicate_owner(&crate::predicate_bytecode(), chain_id).to_vec()
+}
+
+// Builds the data of a message-to-contract message for the target contract
+#[wasm_bindgen(js_name = messageData)]
+pub fn message_data(contract_id: &[u8], payload: &[u8]) -> Result<Vec<u8>, JsError> {
+    let contract_id: &[u8; 32] = contract_id
+        .try_into()
+        .map_err(|_| JsError::new("contract ID must be 32 bytes"))?;
+    Ok(crate::message_data(contract_id, payload))
+}
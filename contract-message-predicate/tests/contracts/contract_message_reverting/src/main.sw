@@ -0,0 +1,12 @@
+contract;
+
+use contract_message_receiver::MessageReceiver;
+
+// A receiver that can never process a message, used to test refunds
+impl MessageReceiver for Contract {
+    #[storage(read, write)]
+    #[payable]
+    fn process_message(msg_idx: u8) {
+        revert(0);
+    }
+}
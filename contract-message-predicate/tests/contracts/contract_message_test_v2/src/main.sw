@@ -0,0 +1,86 @@
+contract;
+
+use contract_message_receiver::MessageReceiverV2;
+use contract_message_receiver::message::require_relayed_message;
+use contract_message_receiver::message_data::MessageDataReader;
+use std::constants::ZERO_B256;
+
+storage {
+    counter: u64 = 0,
+    sender: b256 = ZERO_B256,
+    amount: u64 = 0,
+    nonce: b256 = ZERO_B256,
+    data1: ContractId = ContractId::from(ZERO_B256),
+    data2: u64 = 0,
+}
+
+// Define verification abi
+abi VerifyMessageData {
+    #[storage(read)]
+    fn test_counter() -> u64;
+    #[storage(read)]
+    fn test_sender() -> b256;
+    #[storage(read)]
+    fn test_amount() -> u64;
+    #[storage(read)]
+    fn test_nonce() -> b256;
+    #[storage(read)]
+    fn test_data1() -> ContractId;
+    #[storage(read)]
+    fn test_data2() -> u64;
+}
+
+// Implement the process_message_v2 function required to be a v2 message receiver
+impl MessageReceiverV2 for Contract {
+    #[storage(read, write)]
+    #[payable]
+    fn process_message_v2(sender: b256, amount: u64, nonce: b256, data_ptr: u64, data_len: u64) {
+        require_relayed_message(sender, amount, nonce, data_ptr, data_len);
+
+        storage.counter.write(0); // Temporary fix for: https://github.com/FuelLabs/sway/issues/4634
+        storage.counter.write(storage.counter.read() + 1);
+        storage.sender.write(sender);
+        storage.amount.write(amount);
+        storage.nonce.write(nonce);
+
+        // Parse the message data
+        let mut reader = MessageDataReader::from_raw_parts(data_ptr, data_len);
+        storage.data1.write(reader.read_contract_id());
+        if (reader.remaining() >= 8) {
+            storage.data2.write(reader.read_u64());
+        }
+    }
+}
+
+// Implement simple getters for testing purposes
+impl VerifyMessageData for Contract {
+    #[storage(read)]
+    fn test_counter() -> u64 {
+        storage.counter.read()
+    }
+
+    #[storage(read)]
+    fn test_sender() -> b256 {
+        storage.sender.read()
+    }
+
+    #[storage(read)]
+    fn test_amount() -> u64 {
+        storage.amount.read()
+    }
+
+    #[storage(read)]
+    fn test_nonce() -> b256 {
+        storage.nonce.read()
+    }
+
+    #[storage(read)]
+    fn test_data1() -> ContractId {
+        storage.data1.read()
+    }
+
+    #[storage(read)]
+    fn test_data2() -> u64 {
+        storage.data2.read()
+    }
+}
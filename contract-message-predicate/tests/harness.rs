@@ -10,11 +10,21 @@ mod success {
     use std::str::FromStr;
 
     use crate::utils::{builder, environment as env};
+    #[cfg(feature = "indexer")]
+    use fuel_contract_message_predicate::indexer::{self, RelayIndexer};
+    use fuel_contract_message_predicate::{
+        allowlist::ContractAllowlist,
+        layout_policy::TxLayoutPolicy,
+        relay_event::{RelayEvent, RelayOutcome},
+        selector::{self, AbiType},
+        sender_allowlist::SenderAllowlist,
+        MessageReceiverVersion,
+    };
     use fuels::{
         prelude::{Address, AssetId, ContractId, TxParameters},
         test_helpers::DEFAULT_COIN_AMOUNT,
-        tx::Bytes32,
-        types::Bits256,
+        tx::{Bytes32, Output},
+        types::{bech32::Bech32Address, coin_type::CoinType, input::Input, Bits256},
     };
 
     pub const RANDOM_WORD: u64 = 54321u64;
@@ -121,194 +131,2227 @@ mod success {
             .unwrap();
         assert_eq!(test_contract_balance, 100);
     }
-}
 
-// Test the cases where the transaction should panic due to the
-// predicate script failing to validate the transaction requirements
-mod fail {
-    use std::str::FromStr;
+    #[tokio::test]
+    async fn relay_message_with_all_data_types() {
+        let data_word = RANDOM_WORD;
+        let data_bytes = Bits256(Bytes32::from_str(RANDOM_SALT).unwrap().into());
+        let data_address = Address::from_str(RANDOM_SALT2).unwrap();
+        let data_byte = 0xa5u8;
+        let data_short = 0xbeefu16;
+        let data_tail = vec![1u8, 2u8, 3u8, 4u8, 5u8];
 
-    use crate::utils::{builder, environment as env};
+        let message_data = env::full_message_data(
+            data_word,
+            RANDOM_SALT,
+            RANDOM_SALT2,
+            data_byte,
+            data_short,
+            &data_tail,
+        )
+        .await;
 
-    use fuel_tx::ConsensusParameters;
-    use fuels::{
-        accounts::Account,
-        prelude::{Address, AssetId, Salt, TxParameters},
-        test_helpers::DEFAULT_COIN_AMOUNT,
-        types::{
-            coin::{Coin, CoinStatus::Unspent},
-            coin_type::CoinType,
-            input::Input,
-            transaction_builders::{ScriptTransactionBuilder, TransactionBuilder},
-            unresolved_bytes::UnresolvedBytes,
-        },
-    };
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
 
-    pub const RANDOM_WORD: u64 = 54321u64;
-    pub const RANDOM_WORD2: u64 = 123456u64;
-    pub const RANDOM_SALT: &str =
-        "0xf55c10bc8307552781a896ebd5e6d2b9278b4177b8bca400d3e7710eee293786";
-    pub const RANDOM_SALT2: &str =
-        "0xd5f55c10bc830755278e6d2b9278b4177b8bca401a896eb0d3e7710eee293786";
-    pub const RANDOM_SALT3: &str =
-        "0xd5f55c10bc830755278e6d2b9278b4177b8bca401a896eb0d3e7710eee293786";
+        let (wallet, test_contract, contract_input, coin_inputs, message_inputs) =
+            env::setup_environment(vec![coin], vec![message]).await;
+
+        let _receipts = env::relay_message_to_contract(
+            &wallet,
+            message_inputs[0].clone(),
+            contract_input.clone(),
+            coin_inputs[0].clone(),
+        )
+        .await;
+
+        // Verify test contract read every value from the message data
+        let test_contract_id: ContractId = test_contract.contract_id().into();
+        let methods = test_contract.methods();
+        assert_eq!(methods.test_counter().call().await.unwrap().value, 1);
+        assert_eq!(
+            methods.test_sender().call().await.unwrap().value,
+            Address::from_str(env::MESSAGE_SENDER_ADDRESS).unwrap()
+        );
+        assert_eq!(methods.test_amount().call().await.unwrap().value, 100);
+        assert_eq!(
+            methods.test_data1().call().await.unwrap().value,
+            test_contract_id
+        );
+        assert_eq!(methods.test_data2().call().await.unwrap().value, data_word);
+        assert_eq!(methods.test_data3().call().await.unwrap().value, data_bytes);
+        assert_eq!(
+            methods.test_data4().call().await.unwrap().value,
+            data_address
+        );
+        assert_eq!(methods.test_data5().call().await.unwrap().value, data_byte);
+        assert_eq!(methods.test_data6().call().await.unwrap().value, data_short);
+        assert_eq!(methods.test_data7().call().await.unwrap().value, data_tail);
+    }
 
     #[tokio::test]
-    #[should_panic(expected = "InputNotFound")]
-    async fn relay_message_with_missing_message() {
-        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
-        let (wallet, _, contract_input, coin_inputs, _) =
-            env::setup_environment(vec![coin], vec![]).await;
+    async fn relay_message_from_configured_trusted_sender() {
+        let trusted_sender = Address::from_str(env::MESSAGE_SENDER_ADDRESS2).unwrap();
+        let configurables = env::TestContractConfigurables::new()
+            .set_TRUSTED_SENDER(Bits256(trusted_sender.into()));
 
-        // Transfer coins to a coin with the predicate as an owner
-        let predicate_bytecode = fuel_contract_message_predicate::predicate_bytecode();
+        let message_data = env::prefix_configured_contract_id(
+            RANDOM_WORD.to_be_bytes().to_vec(),
+            configurables.clone(),
+        )
+        .await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
 
-        let cparams = wallet.provider().unwrap().consensus_parameters;
-        let predicate_root =
-            Address::from(fuel_contract_message_predicate::predicate_root(&cparams));
-        let _receipt = wallet
-            .transfer(
-                &predicate_root.into(),
-                100,
-                AssetId::default(),
-                TxParameters::default(),
+        let (wallet, test_contract, contract_input, coin_inputs, message_inputs) =
+            env::setup_second_sender_environment(
+                vec![coin],
+                vec![message],
+                fuel_contract_message_predicate::predicate_bytecode(),
+                configurables,
             )
-            .await
-            .unwrap();
-        let predicate_coin = &wallet
-            .provider()
-            .unwrap()
-            .get_coins(&predicate_root.into(), AssetId::default())
-            .await
-            .unwrap()[0];
-
-        let coin_as_message = Input::ResourcePredicate {
-            resource: CoinType::Coin(Coin {
-                amount: 100,
-                block_created: 0,
-                asset_id: AssetId::default(),
-                utxo_id: predicate_coin.utxo_id,
-                maturity: 0,
-                owner: predicate_root.into(),
-                status: Unspent,
-            }),
-            code: predicate_bytecode,
-            data: UnresolvedBytes::new(vec![]),
-        };
+            .await;
 
-        let (mut tx, _, _) = builder::build_contract_message_tx(
-            coin_as_message,
-            &vec![contract_input.clone(), coin_inputs[0].clone()],
-            &[],
-            TxParameters::default(),
+        let _receipts = env::relay_message_to_contract(
+            &wallet,
+            message_inputs[0].clone(),
+            contract_input.clone(),
+            coin_inputs[0].clone(),
         )
         .await;
 
-        // Note: tx inputs[coin_message, contract, coin], tx outputs[contract, change, variable]
-        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+        // Verify test contract accepted the message from the configured sender
+        let methods = test_contract.methods();
+        assert_eq!(methods.test_counter().call().await.unwrap().value, 1);
+        assert_eq!(
+            methods.test_sender().call().await.unwrap().value,
+            trusted_sender
+        );
+        assert_eq!(
+            methods.test_data2().call().await.unwrap().value,
+            RANDOM_WORD
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "ContractNotInInputs")]
-    async fn relay_message_with_missing_contract() {
-        let message_data = env::prefix_contract_id(vec![]).await;
+    async fn relay_message_marks_message_processed() {
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
         let message = (100, message_data);
         let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
-        let (wallet, _, _, coin_inputs, message_inputs) =
+
+        let (wallet, test_contract, contract_input, coin_inputs, message_inputs) =
             env::setup_environment(vec![coin], vec![message]).await;
 
-        let (mut tx, _, _) = builder::build_contract_message_tx(
+        // Compute the message ID the same way as fuel-tx
+        let message_id = match &message_inputs[0] {
+            Input::ResourcePredicate {
+                resource: CoinType::Message(message),
+                ..
+            } => fuel_contract_message_predicate::message_id(
+                &message.sender.hash().into(),
+                &message.recipient.hash().into(),
+                &message.nonce.into(),
+                message.amount,
+                &message.data,
+            ),
+            _ => unreachable!("message inputs are predicate messages"),
+        };
+
+        let methods = test_contract.methods();
+        let processed = methods.test_processed(Bits256(message_id));
+        assert!(!processed.call().await.unwrap().value);
+
+        let _receipts = env::relay_message_to_contract(
+            &wallet,
             message_inputs[0].clone(),
-            &vec![coin_inputs[0].clone()],
-            &[],
-            TxParameters::default(),
+            contract_input.clone(),
+            coin_inputs[0].clone(),
         )
         .await;
 
-        // Note: tx inputs[message, coin], tx outputs[change, variable]
-        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+        // Verify the test contract computed the same message ID
+        let processed = methods.test_processed(Bits256(message_id));
+        assert!(processed.call().await.unwrap().value);
     }
 
+    #[cfg(feature = "indexer")]
     #[tokio::test]
-    #[should_panic(expected = "ContractNotFound")]
-    async fn relay_message_with_wrong_contract() {
-        let message_data_bad = Salt::from_str(RANDOM_SALT).unwrap().to_vec();
-        let message = (100, message_data_bad);
-        let coin = (1_000_000, AssetId::default());
+    async fn index_relayed_message_from_node() {
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
         let (wallet, _, contract_input, coin_inputs, message_inputs) =
             env::setup_environment(vec![coin], vec![message]).await;
 
-        let (mut tx, _, _) = builder::build_contract_message_tx(
+        // Compute the message ID the same way as fuel-tx
+        let message_id = match &message_inputs[0] {
+            Input::ResourcePredicate {
+                resource: CoinType::Message(message),
+                ..
+            } => fuel_contract_message_predicate::message_id(
+                &message.sender.hash().into(),
+                &message.recipient.hash().into(),
+                &message.nonce.into(),
+                message.amount,
+                &message.data,
+            ),
+            _ => unreachable!("message inputs are predicate messages"),
+        };
+
+        let _receipts = env::relay_message_to_contract(
+            &wallet,
             message_inputs[0].clone(),
-            &vec![contract_input.clone(), coin_inputs[0].clone()],
-            &[],
-            TxParameters::default(),
+            contract_input,
+            coin_inputs[0].clone(),
         )
         .await;
 
-        // Note: tx inputs[message, contract, coin], tx outputs[contract, change, variable]
-        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+        // Scan all blocks of the local node, including the one deploying the test contract
+        let provider = wallet.provider().unwrap();
+        let indexer = RelayIndexer::temporary(fuel_tx::ConsensusParameters::default()).unwrap();
+        let relays = indexer.index_from_node(&provider.client).await.unwrap();
+        let latest_height = indexer::latest_block_height(&provider.client)
+            .await
+            .unwrap();
+        assert_eq!(indexer.next_height().unwrap(), latest_height + 1);
+        assert_eq!(relays.len(), 1);
+
+        // Verify the relay was indexed with its target contract, amount and outcome
+        let relays = indexer.relays_of_message(&message_id).unwrap();
+        assert_eq!(relays.len(), 1);
+        assert_eq!(relays[0].contract_id, env::test_contract_id());
+        assert_eq!(relays[0].amount, 100);
+        assert_eq!(relays[0].outcome, RelayOutcome::Success);
+        assert!(indexer.is_delivered(&message_id).unwrap());
+        assert_eq!(
+            indexer
+                .relays_to_contract(&env::test_contract_id())
+                .unwrap(),
+            relays
+        );
     }
 
     #[tokio::test]
-    #[should_panic(expected = "The transaction contains a predicate which failed to validate")]
-    async fn relay_multiple_messages() {
-        let message_data1 = env::message_data(RANDOM_WORD, RANDOM_SALT3, RANDOM_SALT).await;
-        let message1 = (100, message_data1);
-        let message_data2 = env::message_data(RANDOM_WORD2, RANDOM_SALT2, RANDOM_SALT3).await;
-        let message2 = (150, message_data2);
-        let message_data3 = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
-        let message3 = (200, message_data3);
+    async fn relay_message_with_recoverable_predicate() {
+        let recovery_address = Address::from_str(RANDOM_SALT).unwrap();
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
         let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
-        let (wallet, _, contract_input, coin_inputs, message_inputs) =
-            env::setup_environment(vec![coin], vec![message1, message2, message3]).await;
 
-        let (mut tx, _, _) = builder::build_contract_message_tx(
+        let (wallet, test_contract, contract_input, coin_inputs, message_inputs) =
+            env::setup_recoverable_environment(vec![coin], vec![message], &recovery_address.into())
+                .await;
+
+        let _receipts = env::relay_message_to_contract(
+            &wallet,
             message_inputs[0].clone(),
-            &vec![
-                message_inputs[1].clone(),
-                message_inputs[2].clone(),
-                contract_input.clone(),
-                coin_inputs[0].clone(),
-            ],
-            &[],
+            contract_input,
+            coin_inputs[0].clone(),
+        )
+        .await;
+
+        // Verify the message was relayed to the test contract
+        let methods = test_contract.methods();
+        assert_eq!(methods.test_counter().call().await.unwrap().value, 1);
+    }
+
+    #[tokio::test]
+    async fn sweep_coin_and_message_sent_to_recoverable_predicate() {
+        let recovery_address = Address::from_str(RANDOM_SALT).unwrap();
+        let predicate = fuel_contract_message_predicate::recoverable_predicate_bytecode(
+            &recovery_address.into(),
+        );
+        // Note: a message without data is a plain transfer, so it is swept like a coin
+        let message = (50, vec![]);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, _, _, _, message_inputs) =
+            env::setup_recoverable_environment(vec![coin], vec![message], &recovery_address.into())
+                .await;
+        let predicate_coin = env::transfer_to_predicate(&wallet, predicate, 100).await;
+
+        let mut tx = builder::build_sweep_tx(
+            &[predicate_coin, message_inputs[0].clone()],
+            recovery_address,
             TxParameters::default(),
         )
         .await;
 
-        // Note: tx inputs[message1, message2, message3, contract, coin], tx outputs[change, variable]
+        // Note: tx inputs[coin, message], tx outputs[change]
         let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+
+        // Verify the coin and the message amount reached the recovery address
+        let provider = wallet.provider().unwrap();
+        let recovery_balance = provider
+            .get_asset_balance(&Bech32Address::from(recovery_address), AssetId::default())
+            .await
+            .unwrap();
+        assert_eq!(recovery_balance, 150);
     }
 
     #[tokio::test]
-    #[should_panic(expected = "The transaction contains a predicate which failed to validate")]
-    async fn relay_message_with_invalid_script() {
-        let message_data = env::prefix_contract_id(vec![]).await;
+    async fn relay_wallet_owned_message() {
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
         let message = (100, message_data);
         let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
-        let (wallet, _, contract_input, coin_inputs, message_inputs) =
-            env::setup_environment(vec![coin], vec![message]).await;
 
-        let (_tx, tx_inputs, tx_outputs) = builder::build_contract_message_tx(
+        // Note: messages owned by the wallet can be spent by any script
+        let (wallet, test_contract, contract_input, coin_inputs, message_inputs) =
+            env::setup_signed_environment(vec![coin], vec![message]).await;
+
+        let _receipts = env::relay_message_to_contract(
+            &wallet,
             message_inputs[0].clone(),
-            &vec![contract_input.clone(), coin_inputs[0].clone()],
-            &[],
-            TxParameters::default(),
+            contract_input.clone(),
+            coin_inputs[0].clone(),
         )
         .await;
 
-        // Modify the script bytecode
-        let mut modified_tx = ScriptTransactionBuilder::prepare_transfer(
-            tx_inputs,
-            tx_outputs,
+        let methods = test_contract.methods();
+        assert_eq!(methods.test_counter().call().await.unwrap().value, 1);
+    }
+
+    #[tokio::test]
+    async fn relay_message_to_v2_receiver() {
+        let message_data = env::prefix_contract_v2_id(RANDOM_WORD.to_be_bytes().to_vec()).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let version = MessageReceiverVersion::V2;
+        let (wallet, _, _, coin_inputs, message_inputs) =
+            env::setup_version_environment(vec![coin], vec![message], version).await;
+        let (test_contract, contract_input) = env::deploy_test_contract_v2(&wallet).await;
+
+        let (mut tx, _, _) = builder::build_message_tx_with_script(
+            version.script_bytecode(),
+            message_inputs[0].clone(),
+            &[contract_input, coin_inputs[0].clone()],
+            &[],
             TxParameters::default(),
         )
-        .set_script(vec![0u8, 1u8, 2u8, 3u8])
-        .set_consensus_parameters(ConsensusParameters::default())
-        .build()
-        .unwrap();
+        .await;
 
         // Note: tx inputs[message, contract, coin], tx outputs[contract, change, variable]
-        let _receipts = env::sign_and_call_tx(&wallet, &mut modified_tx).await;
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+
+        // Verify the v2 test contract received the message fields as arguments
+        let nonce = match &message_inputs[0] {
+            Input::ResourcePredicate {
+                resource: CoinType::Message(message),
+                ..
+            } => message.nonce,
+            _ => unreachable!("message inputs are predicate messages"),
+        };
+        let test_contract_id: ContractId = test_contract.contract_id().into();
+        let message_sender = Address::from_str(env::MESSAGE_SENDER_ADDRESS).unwrap();
+        let methods = test_contract.methods();
+        assert_eq!(methods.test_counter().call().await.unwrap().value, 1);
+        assert_eq!(
+            methods.test_sender().call().await.unwrap().value,
+            Bits256(message_sender.into())
+        );
+        assert_eq!(methods.test_amount().call().await.unwrap().value, 100);
+        assert_eq!(
+            methods.test_nonce().call().await.unwrap().value,
+            Bits256(nonce.into())
+        );
+        assert_eq!(
+            methods.test_data1().call().await.unwrap().value,
+            test_contract_id
+        );
+        assert_eq!(
+            methods.test_data2().call().await.unwrap().value,
+            RANDOM_WORD
+        );
+
+        // Verify the message value was received by the v2 test contract
+        let provider = wallet.provider().unwrap();
+        let test_contract_balance = provider
+            .get_contract_asset_balance(test_contract.contract_id(), AssetId::default())
+            .await
+            .unwrap();
+        assert_eq!(test_contract_balance, 100);
+    }
+
+    #[tokio::test]
+    async fn function_selectors_match_abigen() {
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+        let (wallet, test_contract, _, _, _) = env::setup_environment(vec![coin], vec![]).await;
+        let (test_contract_v2, _) = env::deploy_test_contract_v2(&wallet).await;
+
+        // Note: abigen selectors are 8 bytes with the function selector in the last 4
+        let v1_call = test_contract.methods().process_message(0).contract_call;
+        assert_eq!(
+            v1_call.encoded_selector[4..],
+            selector::function_selector("process_message", &[AbiType::U8])
+        );
+        let v2_call = test_contract_v2
+            .methods()
+            .process_message_v2(Bits256::zeroed(), 0, Bits256::zeroed(), 0, 0)
+            .contract_call;
+        assert_eq!(
+            v2_call.encoded_selector[4..],
+            selector::function_selector(
+                "process_message_v2",
+                &[
+                    AbiType::B256,
+                    AbiType::U64,
+                    AbiType::B256,
+                    AbiType::U64,
+                    AbiType::U64
+                ]
+            )
+        );
+
+        // Verify the selectors embedded in the scripts are the ones abigen calls
+        let v1_script = MessageReceiverVersion::V1.script_bytecode();
+        let v2_script = MessageReceiverVersion::V2.script_bytecode();
+        assert_eq!(
+            selector::script_selector(&v1_script).unwrap(),
+            v1_call.encoded_selector[4..]
+        );
+        assert_eq!(
+            selector::script_selector(&v2_script).unwrap(),
+            v2_call.encoded_selector[4..]
+        );
+    }
+
+    #[tokio::test]
+    async fn relay_time_locked_message_after_min_height() {
+        // Note: the first word after the contract ID is the minimum block height
+        let min_height: u32 = 10;
+        let message_data =
+            env::message_data(u64::from(min_height), RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, test_contract, contract_input, coin_inputs, message_inputs) =
+            env::setup_time_locked_environment(vec![coin], vec![message]).await;
+        let provider = wallet.provider().unwrap();
+        provider
+            .produce_blocks(u64::from(min_height), None)
+            .await
+            .unwrap();
+
+        let (mut tx, _, _) = builder::build_contract_message_tx(
+            message_inputs[0].clone(),
+            &[contract_input, coin_inputs[0].clone()],
+            &[],
+            TxParameters::default().set_maturity(min_height),
+        )
+        .await;
+
+        // Note: tx inputs[message, contract, coin], tx outputs[contract, change, variable]
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+
+        // Verify the message was relayed to the test contract
+        let methods = test_contract.methods();
+        assert_eq!(methods.test_counter().call().await.unwrap().value, 1);
+        assert_eq!(
+            methods.test_data2().call().await.unwrap().value,
+            u64::from(min_height)
+        );
+    }
+
+    #[tokio::test]
+    async fn relay_message_with_relayer_fee() {
+        // Note: the first word after the contract ID is the max relayer fee
+        let max_fee = 20;
+        let fee = 15u64;
+        let message_data = env::message_data(max_fee, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, test_contract, contract_input, coin_inputs, message_inputs) =
+            env::setup_fee_environment(vec![coin], vec![message]).await;
+        let provider = wallet.provider().unwrap();
+        let relayer_balance = provider
+            .get_asset_balance(wallet.address(), AssetId::default())
+            .await
+            .unwrap();
+
+        // Note: the relayer fee is passed as script data and paid to the change output
+        let (mut tx, _, _) = builder::build_message_tx_with_script_data(
+            fuel_contract_message_predicate::fee_script_bytecode(),
+            fee.to_be_bytes().to_vec(),
+            message_inputs[0].clone(),
+            &[contract_input, coin_inputs[0].clone()],
+            &[],
+            TxParameters::default(),
+        )
+        .await;
+
+        // Note: tx inputs[message, contract, coin], tx outputs[contract, change, variable]
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+
+        // Verify the message was relayed to the test contract
+        let methods = test_contract.methods();
+        assert_eq!(methods.test_counter().call().await.unwrap().value, 1);
+        assert_eq!(methods.test_amount().call().await.unwrap().value, 100);
+        assert_eq!(methods.test_data2().call().await.unwrap().value, max_fee);
+
+        // Verify the message value was split between the test contract and the relayer
+        let test_contract_balance = provider
+            .get_contract_asset_balance(test_contract.contract_id(), AssetId::default())
+            .await
+            .unwrap();
+        assert_eq!(test_contract_balance, 100 - fee);
+        let new_relayer_balance = provider
+            .get_asset_balance(wallet.address(), AssetId::default())
+            .await
+            .unwrap();
+        assert_eq!(new_relayer_balance, relayer_balance + fee);
+    }
+
+    #[tokio::test]
+    async fn relay_self_funded_message() {
+        let fee_cap = 20;
+        let message_data = fuel_contract_message_predicate::self_funded_message_data(
+            &env::test_contract_id(),
+            fee_cap,
+            &RANDOM_WORD.to_be_bytes(),
+        );
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, test_contract, contract_input, coin_inputs, message_inputs) =
+            env::setup_self_funded_environment(vec![coin], vec![message]).await;
+        let provider = wallet.provider().unwrap();
+        let relayer_balance = provider
+            .get_asset_balance(wallet.address(), AssetId::default())
+            .await
+            .unwrap();
+
+        // Note: the relayer coin pays the max fee, which is (gas limit + gas per byte * bytes) *
+        // gas price / gas price factor, about 11 with the default consensus parameters
+        let gas_limit = 1_000_000;
+        let gas_price = 10_000;
+        let (mut tx, _, _) = builder::build_message_tx_with_script(
+            fuel_contract_message_predicate::self_funded_script_bytecode(),
+            message_inputs[0].clone(),
+            &[contract_input, coin_inputs[0].clone()],
+            &[],
+            TxParameters::default()
+                .set_gas_price(gas_price)
+                .set_gas_limit(gas_limit),
+        )
+        .await;
+
+        // Note: tx inputs[message, contract, coin], tx outputs[contract, change, variable]
+        let receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+
+        // Verify the message was relayed to the test contract
+        let methods = test_contract.methods();
+        assert_eq!(methods.test_counter().call().await.unwrap().value, 1);
+        assert_eq!(methods.test_amount().call().await.unwrap().value, 100);
+
+        // Verify the fee charged for the transaction was taken from the message amount and
+        // the relayer got its coin back
+        let fee = env::charged_fee(&tx, &receipts);
+        assert!(fee > 0);
+        assert!(fee <= fee_cap);
+        let test_contract_balance = provider
+            .get_contract_asset_balance(test_contract.contract_id(), AssetId::default())
+            .await
+            .unwrap();
+        assert_eq!(test_contract_balance, 100 - fee);
+        let new_relayer_balance = provider
+            .get_asset_balance(wallet.address(), AssetId::default())
+            .await
+            .unwrap();
+        assert_eq!(new_relayer_balance, relayer_balance);
+    }
+
+    #[tokio::test]
+    async fn relay_self_funded_message_again_after_revert() {
+        // Note: the max fee of the transaction is about 11, more than the fee cap
+        let fee_cap = 5;
+        let message_data = fuel_contract_message_predicate::self_funded_message_data(
+            &env::test_contract_id(),
+            fee_cap,
+            &RANDOM_WORD.to_be_bytes(),
+        );
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, test_contract, contract_input, coin_inputs, message_inputs) =
+            env::setup_self_funded_environment(vec![coin], vec![message]).await;
+        let provider = wallet.provider().unwrap();
+        let relayer_balance = provider
+            .get_asset_balance(wallet.address(), AssetId::default())
+            .await
+            .unwrap();
+
+        let (mut tx, _, _) = builder::build_message_tx_with_script(
+            fuel_contract_message_predicate::self_funded_script_bytecode(),
+            message_inputs[0].clone(),
+            &[contract_input.clone(), coin_inputs[0].clone()],
+            &[],
+            TxParameters::default()
+                .set_gas_price(10_000)
+                .set_gas_limit(1_000_000),
+        )
+        .await;
+
+        // Verify the transaction was executed and the script reverted
+        let error = env::try_sign_and_call_tx(&wallet, &mut tx)
+            .await
+            .unwrap_err();
+        let (revert_code, receipts) = env::revert_receipts(error);
+        assert_eq!(
+            revert_code,
+            fuel_contract_message_predicate::FEE_CAP_EXCEEDED_REVERT_CODE
+        );
+
+        // Verify the relayer paid the fee and the message was not spent
+        let fee = env::charged_fee(&tx, &receipts);
+        let new_relayer_balance = provider
+            .get_asset_balance(wallet.address(), AssetId::default())
+            .await
+            .unwrap();
+        assert_eq!(new_relayer_balance, relayer_balance - fee);
+        let test_contract_balance = provider
+            .get_contract_asset_balance(test_contract.contract_id(), AssetId::default())
+            .await
+            .unwrap();
+        assert_eq!(test_contract_balance, 0);
+
+        // Note: the retry pays the change of the reverted relay, and with no gas price it has no
+        // fee to take from the message
+        let gas_coin = provider
+            .get_coins(wallet.address(), AssetId::default())
+            .await
+            .unwrap()
+            .remove(0);
+        let (mut tx, _, _) = builder::build_message_tx_with_script(
+            fuel_contract_message_predicate::self_funded_script_bytecode(),
+            message_inputs[0].clone(),
+            &[
+                contract_input,
+                Input::resource_signed(CoinType::Coin(gas_coin), 0),
+            ],
+            &[],
+            TxParameters::default(),
+        )
+        .await;
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+
+        // Verify the message was relayed in full
+        let methods = test_contract.methods();
+        assert_eq!(methods.test_counter().call().await.unwrap().value, 1);
+        let test_contract_balance = provider
+            .get_contract_asset_balance(test_contract.contract_id(), AssetId::default())
+            .await
+            .unwrap();
+        assert_eq!(test_contract_balance, 100);
+    }
+
+    #[tokio::test]
+    async fn relay_message_to_contract_with_pinned_code_root() {
+        // Note: the L1 side computes the expected code root from the receiver binary
+        let code_root =
+            fuel_contract_message_predicate::contract_code_root(env::TEST_RECEIVER_CONTRACT_BINARY)
+                .unwrap();
+        let message_data = fuel_contract_message_predicate::pinned_message_data(
+            &env::test_contract_id(),
+            &code_root,
+            &RANDOM_WORD.to_be_bytes(),
+        );
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, test_contract, contract_input, coin_inputs, message_inputs) =
+            env::setup_pinned_environment(vec![coin], vec![message]).await;
+
+        let (mut tx, _, _) = builder::build_message_tx_with_script(
+            fuel_contract_message_predicate::pinned_script_bytecode(),
+            message_inputs[0].clone(),
+            &[contract_input, coin_inputs[0].clone()],
+            &[],
+            TxParameters::default(),
+        )
+        .await;
+
+        // Note: tx inputs[message, contract, coin], tx outputs[contract, change, variable]
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+
+        // Verify the message was relayed to the test contract
+        let methods = test_contract.methods();
+        assert_eq!(methods.test_counter().call().await.unwrap().value, 1);
+        assert_eq!(methods.test_amount().call().await.unwrap().value, 100);
+    }
+
+    #[tokio::test]
+    async fn relay_message_emits_relay_event() {
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, test_contract, contract_input, coin_inputs, message_inputs) =
+            env::setup_event_environment(vec![coin], vec![message]).await;
+
+        // Compute the message ID the same way as fuel-tx
+        let message_id = match &message_inputs[0] {
+            Input::ResourcePredicate {
+                resource: CoinType::Message(message),
+                ..
+            } => fuel_contract_message_predicate::message_id(
+                &message.sender.hash().into(),
+                &message.recipient.hash().into(),
+                &message.nonce.into(),
+                message.amount,
+                &message.data,
+            ),
+            _ => unreachable!("message inputs are predicate messages"),
+        };
+
+        let (mut tx, _, _) = builder::build_message_tx_with_script(
+            fuel_contract_message_predicate::event_script_bytecode(),
+            message_inputs[0].clone(),
+            &[contract_input, coin_inputs[0].clone()],
+            &[],
+            TxParameters::default(),
+        )
+        .await;
+
+        // Note: tx inputs[message, contract, coin], tx outputs[contract, change, variable]
+        let receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+
+        // Verify the relay event describes the relay
+        assert_eq!(
+            RelayEvent::from_receipts(&receipts),
+            Some(RelayEvent {
+                message_id,
+                contract_id: env::test_contract_id(),
+                amount: 100,
+                outcome: RelayOutcome::Success,
+            })
+        );
+        let methods = test_contract.methods();
+        assert_eq!(methods.test_counter().call().await.unwrap().value, 1);
+        assert_eq!(methods.test_amount().call().await.unwrap().value, 100);
+    }
+
+    #[tokio::test]
+    async fn relay_message_to_reverting_contract_emits_relay_event() {
+        let message_data = fuel_contract_message_predicate::message_data(
+            &env::reverting_contract_id(),
+            &RANDOM_WORD.to_be_bytes(),
+        );
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, _, _, coin_inputs, message_inputs) =
+            env::setup_event_environment(vec![coin], vec![message]).await;
+        let contract_input = env::deploy_reverting_contract(&wallet).await;
+
+        let (mut tx, _, _) = builder::build_message_tx_with_script(
+            fuel_contract_message_predicate::event_script_bytecode(),
+            message_inputs[0].clone(),
+            &[contract_input, coin_inputs[0].clone()],
+            &[],
+            TxParameters::default(),
+        )
+        .await;
+
+        // Note: the relay event is logged before the call, so the reverted transaction has it too
+        let error = env::try_sign_and_call_tx(&wallet, &mut tx)
+            .await
+            .unwrap_err();
+        let (_, receipts) = env::revert_receipts(error);
+
+        // Verify the relay event records the revert of the receiver
+        let event = RelayEvent::from_receipts(&receipts).unwrap();
+        assert_eq!(event.contract_id, env::reverting_contract_id());
+        assert_eq!(event.amount, 100);
+        assert_eq!(event.outcome, RelayOutcome::Revert(0));
+    }
+
+    #[tokio::test]
+    async fn relay_message_to_allowlisted_contract() {
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        // Note: three contracts are padded to a tree of four leaves
+        let test_contract_id = env::test_contract_id();
+        let allowlist = ContractAllowlist::new(&[
+            Bytes32::from_str(RANDOM_SALT).unwrap().into(),
+            test_contract_id,
+            Bytes32::from_str(RANDOM_SALT2).unwrap().into(),
+        ]);
+        // Note: the relayer proves the target contract is in the allowlist with predicate data
+        let (wallet, test_contract, contract_input, coin_inputs, message_inputs) =
+            env::setup_contract_allowlist_environment(
+                vec![coin],
+                vec![message],
+                &allowlist,
+                &test_contract_id,
+            )
+            .await;
+
+        let _receipts = env::relay_message_to_contract(
+            &wallet,
+            message_inputs[0].clone(),
+            contract_input,
+            coin_inputs[0].clone(),
+        )
+        .await;
+
+        // Verify the message was relayed to the test contract
+        let methods = test_contract.methods();
+        assert_eq!(methods.test_counter().call().await.unwrap().value, 1);
+    }
+
+    #[tokio::test]
+    async fn relay_message_from_allowlisted_sender() {
+        // Note: the test contract also checks the sender, so it trusts the second sender too
+        let trusted_sender = Address::from_str(env::MESSAGE_SENDER_ADDRESS2).unwrap();
+        let configurables = env::TestContractConfigurables::new()
+            .set_TRUSTED_SENDER(Bits256(trusted_sender.into()));
+        let allowlist = SenderAllowlist::new()
+            .with_sender(
+                Address::from_str(env::MESSAGE_SENDER_ADDRESS)
+                    .unwrap()
+                    .into(),
+            )
+            .with_sender(trusted_sender.into());
+
+        let message_data = env::prefix_configured_contract_id(
+            RANDOM_WORD.to_be_bytes().to_vec(),
+            configurables.clone(),
+        )
+        .await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, test_contract, contract_input, coin_inputs, message_inputs) =
+            env::setup_second_sender_environment(
+                vec![coin],
+                vec![message],
+                allowlist.predicate_bytecode(),
+                configurables,
+            )
+            .await;
+
+        let _receipts = env::relay_message_to_contract(
+            &wallet,
+            message_inputs[0].clone(),
+            contract_input,
+            coin_inputs[0].clone(),
+        )
+        .await;
+
+        // Verify the message from the second trusted sender was relayed
+        let methods = test_contract.methods();
+        assert_eq!(methods.test_counter().call().await.unwrap().value, 1);
+        assert_eq!(
+            methods.test_sender().call().await.unwrap().value,
+            trusted_sender
+        );
+    }
+
+    #[tokio::test]
+    async fn relay_message_with_strict_layout() {
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, test_contract, contract_input, coin_inputs, message_inputs) =
+            env::setup_layout_policy_environment(
+                vec![coin],
+                vec![message],
+                &TxLayoutPolicy::default(),
+            )
+            .await;
+
+        // Note: tx inputs[message, contract, coin], tx outputs[contract, change, variable]
+        let _receipts = env::relay_message_to_contract(
+            &wallet,
+            message_inputs[0].clone(),
+            contract_input,
+            coin_inputs[0].clone(),
+        )
+        .await;
+
+        let methods = test_contract.methods();
+        assert_eq!(methods.test_counter().call().await.unwrap().value, 1);
+    }
+
+    #[tokio::test]
+    async fn relay_message_with_sealed_outputs() {
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, test_contract, contract_input, coin_inputs, message_inputs) =
+            env::setup_sealed_outputs_environment(vec![coin], vec![message]).await;
+
+        // Note: tx inputs[message, contract, coin], tx outputs[contract, change, variable]
+        let _receipts = env::relay_message_to_contract(
+            &wallet,
+            message_inputs[0].clone(),
+            contract_input,
+            coin_inputs[0].clone(),
+        )
+        .await;
+
+        let methods = test_contract.methods();
+        let provider = wallet.provider().unwrap();
+        let test_contract_balance = provider
+            .get_contract_asset_balance(test_contract.contract_id(), AssetId::default())
+            .await
+            .unwrap();
+        assert_eq!(methods.test_counter().call().await.unwrap().value, 1);
+        assert_eq!(test_contract_balance, 100);
+    }
+
+    #[tokio::test]
+    async fn relay_message_with_relayer_signature() {
+        // Note: the test wallet relays the message and pays for gas
+        let relayer: [u8; 32] = Address::from(env::test_wallet().address()).into();
+        let payload =
+            env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await[32..].to_vec();
+        let message_data = fuel_contract_message_predicate::relayer_message_data(
+            &env::test_contract_id(),
+            &relayer,
+            &payload,
+        );
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        // Note: the wallet signature of the gas coin is witness 0 and signs the transaction ID
+        let (wallet, test_contract, contract_input, coin_inputs, message_inputs) =
+            env::setup_relayer_environment(vec![coin], vec![message], 0).await;
+
+        let _receipts = env::relay_message_to_contract(
+            &wallet,
+            message_inputs[0].clone(),
+            contract_input,
+            coin_inputs[0].clone(),
+        )
+        .await;
+
+        // Verify the message was relayed to the test contract
+        let methods = test_contract.methods();
+        assert_eq!(methods.test_counter().call().await.unwrap().value, 1);
+    }
+
+    #[tokio::test]
+    async fn relay_message_by_relayer_in_relay_window() {
+        let deadline: u32 = 10;
+        let relayer: [u8; 32] = Address::from(env::test_wallet().address()).into();
+        let message_data = fuel_contract_message_predicate::relay_window_message_data(
+            &env::test_contract_id(),
+            &relayer,
+            u64::from(deadline),
+            &[],
+        );
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        // Note: the wallet signature of the gas coin is witness 0 and signs the transaction ID
+        let (wallet, test_contract, contract_input, coin_inputs, message_inputs) =
+            env::setup_relay_window_environment(vec![coin], vec![message], Some(0)).await;
+
+        let _receipts = env::relay_message_to_contract(
+            &wallet,
+            message_inputs[0].clone(),
+            contract_input,
+            coin_inputs[0].clone(),
+        )
+        .await;
+
+        // Verify the message was relayed (no blocks were produced, so before the deadline)
+        let methods = test_contract.methods();
+        assert_eq!(methods.test_counter().call().await.unwrap().value, 1);
+    }
+
+    #[tokio::test]
+    async fn relay_message_by_anyone_after_relay_window() {
+        // Note: the designated relayer is not the test wallet
+        let deadline: u32 = 10;
+        let relayer: [u8; 32] = Bytes32::from_str(RANDOM_SALT3).unwrap().into();
+        let message_data = fuel_contract_message_predicate::relay_window_message_data(
+            &env::test_contract_id(),
+            &relayer,
+            u64::from(deadline),
+            &[],
+        );
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, test_contract, contract_input, coin_inputs, message_inputs) =
+            env::setup_relay_window_environment(vec![coin], vec![message], None).await;
+
+        let provider = wallet.provider().unwrap();
+        provider
+            .produce_blocks(u64::from(deadline), None)
+            .await
+            .unwrap();
+
+        // Note: a maturity of at least the deadline doesn't need a relayer signature
+        let (mut tx, _, _) = builder::build_contract_message_tx(
+            message_inputs[0].clone(),
+            &[contract_input, coin_inputs[0].clone()],
+            &[],
+            TxParameters::default().set_maturity(deadline),
+        )
+        .await;
+
+        // Note: tx inputs[message, contract, coin], tx outputs[contract, change, variable]
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+
+        let methods = test_contract.methods();
+        assert_eq!(methods.test_counter().call().await.unwrap().value, 1);
+    }
+
+    #[tokio::test]
+    async fn refund_message_after_refund_height() {
+        let refund_height = 5;
+        let message_data = env::refundable_message_data(RANDOM_SALT3, refund_height).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, _, _, coin_inputs, message_inputs) =
+            env::setup_refundable_environment(vec![coin, coin], vec![message]).await;
+        let contract_input = env::deploy_reverting_contract(&wallet).await;
+
+        // Note: a message is only refunded after relaying it to its receiver has failed
+        let error = env::try_relay_message_to_contract(
+            &wallet,
+            message_inputs[0].clone(),
+            contract_input,
+            coin_inputs[0].clone(),
+        )
+        .await
+        .unwrap_err();
+        let (revert_code, _) = env::revert_receipts(error);
+        assert_eq!(revert_code, 0);
+
+        let provider = wallet.provider().unwrap();
+        provider.produce_blocks(refund_height, None).await.unwrap();
+
+        // Note: the refund script requires the first output to pay the refund address
+        let refund_address = Address::from_str(RANDOM_SALT3).unwrap();
+        let (mut tx, _, _) = builder::build_message_tx_with_script(
+            fuel_contract_message_predicate::refund_script_bytecode(),
+            message_inputs[0].clone(),
+            &[coin_inputs[1].clone()],
+            &[Output::coin(refund_address, 100, AssetId::default())],
+            TxParameters::default(),
+        )
+        .await;
+
+        // Note: tx inputs[message, coin], tx outputs[coin, change, variable]
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+
+        // Verify the message value was paid to the refund address
+        let refund_balance = provider
+            .get_asset_balance(&Bech32Address::from(refund_address), AssetId::default())
+            .await
+            .unwrap();
+        assert_eq!(refund_balance, 100);
+    }
+
+    #[tokio::test]
+    async fn refund_message_after_reverted_relay() {
+        // Note: the wallet is the refund address, so it owns the change of the relay
+        let refund_address = Address::from(env::test_wallet().address());
+        let message_data = fuel_contract_message_predicate::refundable_message_data(
+            &env::reverting_contract_id(),
+            &refund_address.into(),
+            u64::MAX,
+            &[],
+        );
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, _, _, coin_inputs, message_inputs) =
+            env::setup_refundable_environment(vec![coin], vec![message]).await;
+        let contract_input = env::deploy_reverting_contract(&wallet).await;
+
+        let (mut relay_tx, _, _) = builder::build_contract_message_tx(
+            message_inputs[0].clone(),
+            &[contract_input, coin_inputs[0].clone()],
+            &[],
+            TxParameters::default(),
+        )
+        .await;
+        let error = env::try_sign_and_call_tx(&wallet, &mut relay_tx)
+            .await
+            .unwrap_err();
+        let (revert_code, _) = env::revert_receipts(error);
+        assert_eq!(revert_code, 0);
+
+        // Note: the refund proves the relay reverted by spending the change coin of the relay
+        let cparams = fuel_tx::ConsensusParameters::default();
+        let relay_tx = fuel_tx::Script::from(relay_tx);
+        let relay_id = fuel_tx::UniqueIdentifier::id(&relay_tx, &cparams);
+        let provider = wallet.provider().unwrap();
+        let proof_coin = provider
+            .get_coins(wallet.address(), AssetId::default())
+            .await
+            .unwrap()
+            .into_iter()
+            .find(|coin| *coin.utxo_id.tx_id() == relay_id)
+            .unwrap();
+        let balance = provider
+            .get_asset_balance(wallet.address(), AssetId::default())
+            .await
+            .unwrap();
+
+        let (mut tx, _, _) = builder::build_message_tx_with_script_data(
+            fuel_contract_message_predicate::refund_script_bytecode(),
+            fuel_contract_message_predicate::refund_proof_script_data(&relay_tx, &cparams),
+            message_inputs[0].clone(),
+            &[Input::resource_signed(CoinType::Coin(proof_coin), 0)],
+            &[Output::coin(refund_address, 100, AssetId::default())],
+            TxParameters::default(),
+        )
+        .await;
+
+        // Note: tx inputs[message, coin], tx outputs[coin, change, variable]
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+
+        // Verify the message value was paid to the refund address before the refund height
+        let refund_balance = provider
+            .get_asset_balance(wallet.address(), AssetId::default())
+            .await
+            .unwrap();
+        assert_eq!(refund_balance, balance + 100);
+    }
+}
+
+// Test the cases where the transaction should panic due to the
+// predicate script failing to validate the transaction requirements
+mod fail {
+    use std::str::FromStr;
+
+    use crate::utils::{builder, environment as env};
+
+    use fuel_contract_message_predicate::{
+        allowlist::ContractAllowlist,
+        layout_policy::{InputKind, TxLayoutPolicy},
+        sender_allowlist::SenderAllowlist,
+        MessageReceiverVersion,
+    };
+    use fuel_tx::{ConsensusParameters, Output};
+    use fuels::{
+        accounts::Account,
+        prelude::{Address, AssetId, Salt, TxParameters},
+        test_helpers::DEFAULT_COIN_AMOUNT,
+        tx::Bytes32,
+        types::{
+            coin::{Coin, CoinStatus::Unspent},
+            coin_type::CoinType,
+            input::Input,
+            transaction_builders::{ScriptTransactionBuilder, TransactionBuilder},
+            unresolved_bytes::UnresolvedBytes,
+        },
+    };
+
+    pub const RANDOM_WORD: u64 = 54321u64;
+    pub const RANDOM_WORD2: u64 = 123456u64;
+    pub const RANDOM_SALT: &str =
+        "0xf55c10bc8307552781a896ebd5e6d2b9278b4177b8bca400d3e7710eee293786";
+    pub const RANDOM_SALT2: &str =
+        "0xd5f55c10bc830755278e6d2b9278b4177b8bca401a896eb0d3e7710eee293786";
+    pub const RANDOM_SALT3: &str =
+        "0xd5f55c10bc830755278e6d2b9278b4177b8bca401a896eb0d3e7710eee293786";
+
+    #[tokio::test]
+    #[should_panic(expected = "InputNotFound")]
+    async fn relay_message_with_missing_message() {
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+        let (wallet, _, contract_input, coin_inputs, _) =
+            env::setup_environment(vec![coin], vec![]).await;
+
+        // Transfer coins to a coin with the predicate as an owner
+        let predicate_bytecode = fuel_contract_message_predicate::predicate_bytecode();
+
+        let cparams = wallet.provider().unwrap().consensus_parameters;
+        let predicate_root =
+            Address::from(fuel_contract_message_predicate::predicate_root(&cparams));
+        let _receipt = wallet
+            .transfer(
+                &predicate_root.into(),
+                100,
+                AssetId::default(),
+                TxParameters::default(),
+            )
+            .await
+            .unwrap();
+        let predicate_coin = &wallet
+            .provider()
+            .unwrap()
+            .get_coins(&predicate_root.into(), AssetId::default())
+            .await
+            .unwrap()[0];
+
+        let coin_as_message = Input::ResourcePredicate {
+            resource: CoinType::Coin(Coin {
+                amount: 100,
+                block_created: 0,
+                asset_id: AssetId::default(),
+                utxo_id: predicate_coin.utxo_id,
+                maturity: 0,
+                owner: predicate_root.into(),
+                status: Unspent,
+            }),
+            code: predicate_bytecode,
+            data: UnresolvedBytes::new(vec![]),
+        };
+
+        let (mut tx, _, _) = builder::build_contract_message_tx(
+            coin_as_message,
+            &vec![contract_input.clone(), coin_inputs[0].clone()],
+            &[],
+            TxParameters::default(),
+        )
+        .await;
+
+        // Note: tx inputs[coin_message, contract, coin], tx outputs[contract, change, variable]
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ContractNotInInputs")]
+    async fn relay_message_with_missing_contract() {
+        let message_data = env::prefix_contract_id(vec![]).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+        let (wallet, _, _, coin_inputs, message_inputs) =
+            env::setup_environment(vec![coin], vec![message]).await;
+
+        let (mut tx, _, _) = builder::build_contract_message_tx(
+            message_inputs[0].clone(),
+            &vec![coin_inputs[0].clone()],
+            &[],
+            TxParameters::default(),
+        )
+        .await;
+
+        // Note: tx inputs[message, coin], tx outputs[change, variable]
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ContractNotFound")]
+    async fn relay_message_with_wrong_contract() {
+        let message_data_bad = Salt::from_str(RANDOM_SALT).unwrap().to_vec();
+        let message = (100, message_data_bad);
+        let coin = (1_000_000, AssetId::default());
+        let (wallet, _, contract_input, coin_inputs, message_inputs) =
+            env::setup_environment(vec![coin], vec![message]).await;
+
+        let (mut tx, _, _) = builder::build_contract_message_tx(
+            message_inputs[0].clone(),
+            &vec![contract_input.clone(), coin_inputs[0].clone()],
+            &[],
+            TxParameters::default(),
+        )
+        .await;
+
+        // Note: tx inputs[message, contract, coin], tx outputs[contract, change, variable]
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "The transaction contains a predicate which failed to validate")]
+    async fn relay_multiple_messages() {
+        let message_data1 = env::message_data(RANDOM_WORD, RANDOM_SALT3, RANDOM_SALT).await;
+        let message1 = (100, message_data1);
+        let message_data2 = env::message_data(RANDOM_WORD2, RANDOM_SALT2, RANDOM_SALT3).await;
+        let message2 = (150, message_data2);
+        let message_data3 = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message3 = (200, message_data3);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+        let (wallet, _, contract_input, coin_inputs, message_inputs) =
+            env::setup_environment(vec![coin], vec![message1, message2, message3]).await;
+
+        let (mut tx, _, _) = builder::build_contract_message_tx(
+            message_inputs[0].clone(),
+            &vec![
+                message_inputs[1].clone(),
+                message_inputs[2].clone(),
+                contract_input.clone(),
+                coin_inputs[0].clone(),
+            ],
+            &[],
+            TxParameters::default(),
+        )
+        .await;
+
+        // Note: tx inputs[message1, message2, message3, contract, coin], tx outputs[change, variable]
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "The transaction contains a predicate which failed to validate")]
+    async fn relay_message_with_invalid_script() {
+        let message_data = env::prefix_contract_id(vec![]).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+        let (wallet, _, contract_input, coin_inputs, message_inputs) =
+            env::setup_environment(vec![coin], vec![message]).await;
+
+        let (_tx, tx_inputs, tx_outputs) = builder::build_contract_message_tx(
+            message_inputs[0].clone(),
+            &vec![contract_input.clone(), coin_inputs[0].clone()],
+            &[],
+            TxParameters::default(),
+        )
+        .await;
+
+        // Modify the script bytecode
+        let mut modified_tx = ScriptTransactionBuilder::prepare_transfer(
+            tx_inputs,
+            tx_outputs,
+            TxParameters::default(),
+        )
+        .set_script(vec![0u8, 1u8, 2u8, 3u8])
+        .set_consensus_parameters(ConsensusParameters::default())
+        .build()
+        .unwrap();
+
+        // Note: tx inputs[message, contract, coin], tx outputs[contract, change, variable]
+        let _receipts = env::sign_and_call_tx(&wallet, &mut modified_tx).await;
+    }
+
+    #[tokio::test]
+    async fn relay_message_from_untrusted_sender() {
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+        let (wallet, test_contract, contract_input, coin_inputs, message_inputs) =
+            env::setup_second_sender_environment(
+                vec![coin],
+                vec![message],
+                fuel_contract_message_predicate::predicate_bytecode(),
+                env::TestContractConfigurables::new(),
+            )
+            .await;
+
+        // Note: the test contract only trusts messages from MESSAGE_SENDER_ADDRESS
+        let error = env::try_relay_message_to_contract(
+            &wallet,
+            message_inputs[0].clone(),
+            contract_input.clone(),
+            coin_inputs[0].clone(),
+        )
+        .await
+        .unwrap_err();
+
+        // Verify the relay reverted on the trusted sender check
+        let (revert_code, receipts) = env::revert_receipts(error);
+        assert_eq!(revert_code, env::FAILED_REQUIRE_SIGNAL);
+        let errors = test_contract
+            .log_decoder()
+            .decode_logs_with_type::<env::MessageReceiverError>(&receipts)
+            .unwrap();
+        assert_eq!(errors, vec![env::MessageReceiverError::UntrustedSender]);
+    }
+
+    #[tokio::test]
+    async fn relay_same_message_twice() {
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+        let (wallet, test_contract, contract_input, coin_inputs, message_inputs) =
+            env::setup_environment(vec![coin, coin], vec![message]).await;
+
+        let _receipts = env::relay_message_to_contract(
+            &wallet,
+            message_inputs[0].clone(),
+            contract_input.clone(),
+            coin_inputs[0].clone(),
+        )
+        .await;
+
+        // Note: the second relay transaction pays for gas with another coin
+        let result = env::try_relay_message_to_contract(
+            &wallet,
+            message_inputs[0].clone(),
+            contract_input.clone(),
+            coin_inputs[1].clone(),
+        )
+        .await;
+        assert!(result.is_err());
+
+        // Verify the message was only processed once
+        let methods = test_contract.methods();
+        assert_eq!(methods.test_counter().call().await.unwrap().value, 1);
+    }
+
+    #[tokio::test]
+    async fn relay_same_message_twice_in_one_transaction() {
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        // Note: messages owned by the wallet can be spent by any script
+        let (wallet, test_contract, contract_input, coin_inputs, message_inputs) =
+            env::setup_signed_environment(vec![coin], vec![message]).await;
+
+        // Note: the replay script calls `process_message` twice for the same message ID
+        let (mut tx, _, _) = builder::build_message_tx_with_script(
+            builder::replay_script_bytecode(),
+            message_inputs[0].clone(),
+            &vec![contract_input.clone(), coin_inputs[0].clone()],
+            &[],
+            TxParameters::default(),
+        )
+        .await;
+
+        // Note: tx inputs[message, contract, coin], tx outputs[contract, change, variable]
+        let error = env::try_sign_and_call_tx(&wallet, &mut tx)
+            .await
+            .unwrap_err();
+
+        // Verify the second call reverted on the processed message check
+        let (revert_code, receipts) = env::revert_receipts(error);
+        assert_eq!(revert_code, env::FAILED_REQUIRE_SIGNAL);
+        let errors = test_contract
+            .log_decoder()
+            .decode_logs_with_type::<env::MessageReceiverError>(&receipts)
+            .unwrap();
+        assert_eq!(errors, vec![env::MessageReceiverError::AlreadyProcessed]);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "The transaction contains a predicate which failed to validate")]
+    async fn relay_v2_message_with_v1_script() {
+        let message_data = env::prefix_contract_v2_id(RANDOM_WORD.to_be_bytes().to_vec()).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+        let (wallet, _, _, coin_inputs, message_inputs) =
+            env::setup_version_environment(vec![coin], vec![message], MessageReceiverVersion::V2)
+                .await;
+        let (_, contract_input) = env::deploy_test_contract_v2(&wallet).await;
+
+        // Note: the v2 predicate only allows the v2 script
+        let (mut tx, _, _) = builder::build_contract_message_tx(
+            message_inputs[0].clone(),
+            &[contract_input, coin_inputs[0].clone()],
+            &[],
+            TxParameters::default(),
+        )
+        .await;
+
+        // Note: tx inputs[message, contract, coin], tx outputs[contract, change, variable]
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert")]
+    async fn relay_message_to_v1_receiver_with_v2_script() {
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let version = MessageReceiverVersion::V2;
+        let (wallet, _, contract_input, coin_inputs, message_inputs) =
+            env::setup_version_environment(vec![coin], vec![message], version).await;
+
+        // Note: the v1 test contract does not implement process_message_v2
+        let (mut tx, _, _) = builder::build_message_tx_with_script(
+            version.script_bytecode(),
+            message_inputs[0].clone(),
+            &[contract_input, coin_inputs[0].clone()],
+            &[],
+            TxParameters::default(),
+        )
+        .await;
+
+        // Note: tx inputs[message, contract, coin], tx outputs[contract, change, variable]
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotAMessage")]
+    async fn process_message_without_message_input() {
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+        let (_, test_contract, _, _, _) = env::setup_environment(vec![coin], vec![]).await;
+
+        // Call the receiver directly, so input 0 is not a message
+        test_contract
+            .methods()
+            .process_message(0)
+            .call()
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert")]
+    async fn relay_refundable_message_to_reverting_receiver() {
+        let message_data = env::refundable_message_data(RANDOM_SALT3, 5).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, _, _, coin_inputs, message_inputs) =
+            env::setup_refundable_environment(vec![coin], vec![message]).await;
+        let contract_input = env::deploy_reverting_contract(&wallet).await;
+
+        // Note: the predicate accepts the relay script, but the receiver always reverts
+        let _receipts = env::relay_message_to_contract(
+            &wallet,
+            message_inputs[0].clone(),
+            contract_input,
+            coin_inputs[0].clone(),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert")]
+    async fn refund_message_before_refund_height() {
+        let message_data = env::refundable_message_data(RANDOM_SALT3, 1000).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, _, _, coin_inputs, message_inputs) =
+            env::setup_refundable_environment(vec![coin], vec![message]).await;
+
+        let refund_address = Address::from_str(RANDOM_SALT3).unwrap();
+        let (mut tx, _, _) = builder::build_message_tx_with_script(
+            fuel_contract_message_predicate::refund_script_bytecode(),
+            message_inputs[0].clone(),
+            &[coin_inputs[0].clone()],
+            &[Output::coin(refund_address, 100, AssetId::default())],
+            TxParameters::default(),
+        )
+        .await;
+
+        // Note: tx inputs[message, coin], tx outputs[coin, change, variable]
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert")]
+    async fn refund_message_to_wrong_address() {
+        let refund_height = 5;
+        let message_data = env::refundable_message_data(RANDOM_SALT3, refund_height).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, _, _, coin_inputs, message_inputs) =
+            env::setup_refundable_environment(vec![coin], vec![message]).await;
+        let provider = wallet.provider().unwrap();
+        provider.produce_blocks(refund_height, None).await.unwrap();
+
+        // Note: the refund is paid to the wallet instead of the refund address
+        let (mut tx, _, _) = builder::build_message_tx_with_script(
+            fuel_contract_message_predicate::refund_script_bytecode(),
+            message_inputs[0].clone(),
+            &[coin_inputs[0].clone()],
+            &[Output::coin(
+                wallet.address().into(),
+                100,
+                AssetId::default(),
+            )],
+            TxParameters::default(),
+        )
+        .await;
+
+        // Note: tx inputs[message, coin], tx outputs[coin, change, variable]
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "The transaction contains a predicate which failed to validate")]
+    async fn relay_time_locked_message_without_maturity() {
+        let message_data = env::message_data(10, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, _, contract_input, coin_inputs, message_inputs) =
+            env::setup_time_locked_environment(vec![coin], vec![message]).await;
+        let provider = wallet.provider().unwrap();
+        provider.produce_blocks(10, None).await.unwrap();
+
+        // Note: the default maturity of 0 is below the minimum block height
+        let _receipts = env::relay_message_to_contract(
+            &wallet,
+            message_inputs[0].clone(),
+            contract_input,
+            coin_inputs[0].clone(),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "The transaction contains a predicate which failed to validate")]
+    async fn relay_time_locked_message_with_maturity_below_min_height() {
+        let min_height: u32 = 10;
+        let message_data =
+            env::message_data(u64::from(min_height), RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, _, contract_input, coin_inputs, message_inputs) =
+            env::setup_time_locked_environment(vec![coin], vec![message]).await;
+        let provider = wallet.provider().unwrap();
+        provider
+            .produce_blocks(u64::from(min_height), None)
+            .await
+            .unwrap();
+
+        // Note: a maturity below the minimum block height would allow an earlier relay
+        let (mut tx, _, _) = builder::build_contract_message_tx(
+            message_inputs[0].clone(),
+            &[contract_input, coin_inputs[0].clone()],
+            &[],
+            TxParameters::default().set_maturity(min_height - 1),
+        )
+        .await;
+
+        // Note: tx inputs[message, contract, coin], tx outputs[contract, change, variable]
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Revert")]
+    async fn relay_message_with_fee_above_max_fee() {
+        let max_fee = 20;
+        let message_data = env::message_data(max_fee, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, _, contract_input, coin_inputs, message_inputs) =
+            env::setup_fee_environment(vec![coin], vec![message]).await;
+
+        // Note: a greedy relayer asks for more than the max relayer fee
+        let fee: u64 = max_fee + 1;
+        let (mut tx, _, _) = builder::build_message_tx_with_script_data(
+            fuel_contract_message_predicate::fee_script_bytecode(),
+            fee.to_be_bytes().to_vec(),
+            message_inputs[0].clone(),
+            &[contract_input, coin_inputs[0].clone()],
+            &[],
+            TxParameters::default(),
+        )
+        .await;
+
+        // Note: tx inputs[message, contract, coin], tx outputs[contract, change, variable]
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "The transaction contains a predicate which failed to validate")]
+    async fn relay_fee_message_with_greedy_script() {
+        let message_data = env::message_data(20, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, _, contract_input, coin_inputs, message_inputs) =
+            env::setup_fee_environment(vec![coin], vec![message]).await;
+
+        // Note: a greedy relayer uses a script that forwards none of the message amount
+        let (mut tx, _, _) = builder::build_message_tx_with_script(
+            builder::replay_script_bytecode(),
+            message_inputs[0].clone(),
+            &[contract_input, coin_inputs[0].clone()],
+            &[],
+            TxParameters::default(),
+        )
+        .await;
+
+        // Note: tx inputs[message, contract, coin], tx outputs[contract, change, variable]
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+    }
+
+    #[tokio::test]
+    async fn relay_message_to_contract_with_mismatched_code_root() {
+        // Note: the expected code root is the one of another receiver contract
+        let code_root = fuel_contract_message_predicate::contract_code_root(
+            env::REVERTING_RECEIVER_CONTRACT_BINARY,
+        )
+        .unwrap();
+        let message_data = fuel_contract_message_predicate::pinned_message_data(
+            &env::test_contract_id(),
+            &code_root,
+            &RANDOM_WORD.to_be_bytes(),
+        );
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, _, contract_input, coin_inputs, message_inputs) =
+            env::setup_pinned_environment(vec![coin], vec![message]).await;
+
+        let (mut tx, _, _) = builder::build_message_tx_with_script(
+            fuel_contract_message_predicate::pinned_script_bytecode(),
+            message_inputs[0].clone(),
+            &[contract_input, coin_inputs[0].clone()],
+            &[],
+            TxParameters::default(),
+        )
+        .await;
+
+        let error = env::try_sign_and_call_tx(&wallet, &mut tx)
+            .await
+            .unwrap_err();
+
+        // Verify the script reverted on the code root check
+        let (revert_code, _) = env::revert_receipts(error);
+        assert_eq!(
+            revert_code,
+            fuel_contract_message_predicate::CODE_ROOT_MISMATCH_REVERT_CODE
+        );
+    }
+
+    #[tokio::test]
+    async fn relay_self_funded_message_exceeding_fee_cap() {
+        // Note: the max fee of the transaction is about 11, more than the fee cap
+        let fee_cap = 5;
+        let message_data = fuel_contract_message_predicate::self_funded_message_data(
+            &env::test_contract_id(),
+            fee_cap,
+            &RANDOM_WORD.to_be_bytes(),
+        );
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, _, contract_input, coin_inputs, message_inputs) =
+            env::setup_self_funded_environment(vec![coin], vec![message]).await;
+
+        let (mut tx, _, _) = builder::build_message_tx_with_script(
+            fuel_contract_message_predicate::self_funded_script_bytecode(),
+            message_inputs[0].clone(),
+            &[contract_input, coin_inputs[0].clone()],
+            &[],
+            TxParameters::default()
+                .set_gas_price(10_000)
+                .set_gas_limit(1_000_000),
+        )
+        .await;
+
+        let error = env::try_sign_and_call_tx(&wallet, &mut tx)
+            .await
+            .unwrap_err();
+
+        // Verify the script reverted on the fee cap check
+        let (revert_code, _) = env::revert_receipts(error);
+        assert_eq!(
+            revert_code,
+            fuel_contract_message_predicate::FEE_CAP_EXCEEDED_REVERT_CODE
+        );
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "The transaction contains a predicate which failed to validate")]
+    async fn sweep_message_with_data_from_recoverable_predicate() {
+        let recovery_address = Address::from_str(RANDOM_SALT).unwrap();
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, _, _, _, message_inputs) =
+            env::setup_recoverable_environment(vec![coin], vec![message], &recovery_address.into())
+                .await;
+
+        // Note: messages with data can only be relayed
+        let mut tx = builder::build_sweep_tx(
+            &[message_inputs[0].clone()],
+            recovery_address,
+            TxParameters::default(),
+        )
+        .await;
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "The transaction contains a predicate which failed to validate")]
+    async fn sweep_coin_to_other_address_from_recoverable_predicate() {
+        let recovery_address = Address::from_str(RANDOM_SALT).unwrap();
+        let predicate = fuel_contract_message_predicate::recoverable_predicate_bytecode(
+            &recovery_address.into(),
+        );
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, _, _, _, _) = env::setup_environment(vec![coin], vec![]).await;
+        let predicate_coin = env::transfer_to_predicate(&wallet, predicate, 100).await;
+
+        // Note: the sweep pays the wallet instead of the recovery address
+        let mut tx = builder::build_sweep_tx(
+            &[predicate_coin],
+            wallet.address().into(),
+            TxParameters::default(),
+        )
+        .await;
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "The transaction contains a predicate which failed to validate")]
+    async fn relay_script_spending_coin_from_recoverable_predicate() {
+        let recovery_address = Address::from_str(RANDOM_SALT).unwrap();
+        let predicate = fuel_contract_message_predicate::recoverable_predicate_bytecode(
+            &recovery_address.into(),
+        );
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, _, _, _, _) = env::setup_environment(vec![coin], vec![]).await;
+        let predicate_coin = env::transfer_to_predicate(&wallet, predicate, 100).await;
+
+        // Note: the relay script runs without a message, and the coin goes to the wallet instead
+        // of the recovery address
+        let (mut tx, _, _) = builder::build_contract_message_tx(
+            predicate_coin,
+            &[],
+            &[Output::change(
+                wallet.address().into(),
+                0,
+                AssetId::default(),
+            )],
+            TxParameters::default(),
+        )
+        .await;
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "The transaction contains a predicate which failed to validate")]
+    async fn relay_message_spending_coin_from_recoverable_predicate() {
+        let recovery_address = Address::from_str(RANDOM_SALT).unwrap();
+        let predicate = fuel_contract_message_predicate::recoverable_predicate_bytecode(
+            &recovery_address.into(),
+        );
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, _, contract_input, _, message_inputs) =
+            env::setup_recoverable_environment(vec![coin], vec![message], &recovery_address.into())
+                .await;
+        let predicate_coin = env::transfer_to_predicate(&wallet, predicate, 100).await;
+
+        // Note: the transfer spent the wallet coin, so gas is paid with its change
+        let gas_coin = wallet
+            .provider()
+            .unwrap()
+            .get_coins(wallet.address(), AssetId::default())
+            .await
+            .unwrap()
+            .remove(0);
+        let gas_coin = Input::resource_signed(CoinType::Coin(gas_coin), 0);
+
+        // Note: the relay is valid for the message at input 0, but the coin from the predicate
+        // at a later input goes to the change of the wallet instead of the recovery address
+        let (mut tx, _, _) = builder::build_contract_message_tx(
+            message_inputs[0].clone(),
+            &[contract_input, predicate_coin, gas_coin],
+            &[],
+            TxParameters::default(),
+        )
+        .await;
+
+        // Note: tx inputs[message, contract, coin, coin], tx outputs[contract, change, variable]
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "The transaction contains a predicate which failed to validate")]
+    async fn relay_message_to_contract_not_in_allowlist() {
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let allowed_contract_id: [u8; 32] = Bytes32::from_str(RANDOM_SALT).unwrap().into();
+        let allowlist = ContractAllowlist::new(&[
+            allowed_contract_id,
+            Bytes32::from_str(RANDOM_SALT2).unwrap().into(),
+        ]);
+        // Note: the message targets the test contract, so a proof for another contract is used
+        let (wallet, _, contract_input, coin_inputs, message_inputs) =
+            env::setup_contract_allowlist_environment(
+                vec![coin],
+                vec![message],
+                &allowlist,
+                &allowed_contract_id,
+            )
+            .await;
+
+        let _receipts = env::relay_message_to_contract(
+            &wallet,
+            message_inputs[0].clone(),
+            contract_input,
+            coin_inputs[0].clone(),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "The transaction contains a predicate which failed to validate")]
+    async fn relay_message_from_sender_not_in_allowlist() {
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let allowlist = SenderAllowlist::new().with_sender(
+            Address::from_str(env::MESSAGE_SENDER_ADDRESS)
+                .unwrap()
+                .into(),
+        );
+        let (wallet, _, contract_input, coin_inputs, message_inputs) =
+            env::setup_second_sender_environment(
+                vec![coin],
+                vec![message],
+                allowlist.predicate_bytecode(),
+                env::TestContractConfigurables::new(),
+            )
+            .await;
+
+        // Note: the message was sent by the second sender, which is not in the allowlist
+        let _receipts = env::relay_message_to_contract(
+            &wallet,
+            message_inputs[0].clone(),
+            contract_input,
+            coin_inputs[0].clone(),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "The transaction contains a predicate which failed to validate")]
+    async fn strict_layout_with_too_many_inputs() {
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, _, contract_input, coin_inputs, message_inputs) =
+            env::setup_layout_policy_environment(
+                vec![coin, coin],
+                vec![message],
+                &TxLayoutPolicy::default(),
+            )
+            .await;
+
+        // Note: the default policy allows a single gas coin
+        let (mut tx, _, _) = builder::build_contract_message_tx(
+            message_inputs[0].clone(),
+            &[
+                contract_input,
+                coin_inputs[0].clone(),
+                coin_inputs[1].clone(),
+            ],
+            &[],
+            TxParameters::default(),
+        )
+        .await;
+
+        // Note: tx inputs[message, contract, coin, coin], tx outputs[contract, change, variable]
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "The transaction contains a predicate which failed to validate")]
+    async fn strict_layout_with_contract_not_second_input() {
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, _, contract_input, coin_inputs, message_inputs) =
+            env::setup_layout_policy_environment(
+                vec![coin],
+                vec![message],
+                &TxLayoutPolicy::default(),
+            )
+            .await;
+
+        // Note: the target contract input must directly follow the message
+        let (mut tx, _, _) = builder::build_contract_message_tx(
+            message_inputs[0].clone(),
+            &[coin_inputs[0].clone(), contract_input],
+            &[],
+            TxParameters::default(),
+        )
+        .await;
+
+        // Note: tx inputs[message, coin, contract], tx outputs[contract, change, variable]
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "The transaction contains a predicate which failed to validate")]
+    async fn strict_layout_with_wrong_target_contract() {
+        let message_data = env::decode_hex(RANDOM_SALT);
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, _, contract_input, coin_inputs, message_inputs) =
+            env::setup_layout_policy_environment(
+                vec![coin],
+                vec![message],
+                &TxLayoutPolicy::default(),
+            )
+            .await;
+
+        // Note: the contract input is not the one the message targets
+        let _receipts = env::relay_message_to_contract(
+            &wallet,
+            message_inputs[0].clone(),
+            contract_input,
+            coin_inputs[0].clone(),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "The transaction contains a predicate which failed to validate")]
+    async fn strict_layout_with_coin_as_first_output() {
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, _, contract_input, coin_inputs, message_inputs) =
+            env::setup_layout_policy_environment(
+                vec![coin],
+                vec![message],
+                &TxLayoutPolicy::default(),
+            )
+            .await;
+
+        // Note: the first output must be the contract output of the target contract
+        let (mut tx, _, _) = builder::build_contract_message_tx(
+            message_inputs[0].clone(),
+            &[contract_input, coin_inputs[0].clone()],
+            &[Output::coin(wallet.address().into(), 0, AssetId::default())],
+            TxParameters::default(),
+        )
+        .await;
+
+        // Note: tx inputs[message, contract, coin], tx outputs[coin, contract, change, variable]
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "The transaction contains a predicate which failed to validate")]
+    async fn strict_layout_with_disallowed_input_type() {
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+        let policy = TxLayoutPolicy {
+            max_inputs: 4,
+            extra_inputs: vec![InputKind::Coin],
+        };
+
+        let (wallet, _, contract_input, coin_inputs, message_inputs) =
+            env::setup_layout_policy_environment(vec![coin], vec![message, (10, vec![])], &policy)
+                .await;
+
+        // Note: the policy allows enough inputs, but only coins as extra inputs
+        let (mut tx, _, _) = builder::build_contract_message_tx(
+            message_inputs[0].clone(),
+            &[
+                contract_input,
+                coin_inputs[0].clone(),
+                message_inputs[1].clone(),
+            ],
+            &[],
+            TxParameters::default(),
+        )
+        .await;
+
+        // Note: tx inputs[message, contract, coin, message], tx outputs[contract, change, variable]
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "The transaction contains a predicate which failed to validate")]
+    async fn sealed_outputs_with_base_asset_coin_output() {
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, _, contract_input, coin_inputs, message_inputs) =
+            env::setup_sealed_outputs_environment(vec![coin], vec![message]).await;
+
+        // Note: the coin output tries to siphon the message amount to another address
+        let (mut tx, _, _) = builder::build_contract_message_tx(
+            message_inputs[0].clone(),
+            &[contract_input, coin_inputs[0].clone()],
+            &[Output::coin(
+                Address::from_str(RANDOM_SALT3).unwrap(),
+                100,
+                AssetId::default(),
+            )],
+            TxParameters::default(),
+        )
+        .await;
+
+        // Note: tx inputs[message, contract, coin], tx outputs[coin, contract, change, variable]
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "The transaction contains a predicate which failed to validate")]
+    async fn sealed_outputs_with_change_to_non_coin_owner() {
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+
+        let (wallet, _, contract_input, _, message_inputs) =
+            env::setup_sealed_outputs_environment(vec![], vec![message]).await;
+
+        // Note: without gas coins, the change output collects whatever the script does not
+        // forward (e.g. the whole message amount on a revert) for an address of the relayer's choice
+        let (mut tx, _, _) = builder::build_contract_message_tx(
+            message_inputs[0].clone(),
+            &[contract_input],
+            &[Output::change(
+                Address::from_str(RANDOM_SALT3).unwrap(),
+                0,
+                AssetId::default(),
+            )],
+            TxParameters::default(),
+        )
+        .await;
+
+        // Note: tx inputs[message, contract], tx outputs[change, contract, variable]
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "The transaction contains a predicate which failed to validate")]
+    async fn sealed_outputs_with_change_to_message_predicate() {
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let predicate_root = fuel_contract_message_predicate::sealed_outputs_predicate_root(
+            &ConsensusParameters::default(),
+        );
+
+        let (wallet, _, contract_input, _, message_inputs) =
+            env::setup_sealed_outputs_environment(vec![], vec![message]).await;
+
+        // Note: the message predicate owns the message, not a coin input
+        let (mut tx, _, _) = builder::build_contract_message_tx(
+            message_inputs[0].clone(),
+            &[contract_input],
+            &[Output::change(
+                Address::from(predicate_root),
+                0,
+                AssetId::default(),
+            )],
+            TxParameters::default(),
+        )
+        .await;
+
+        // Note: tx inputs[message, contract], tx outputs[change, contract, variable]
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "The transaction contains a predicate which failed to validate")]
+    async fn relay_message_signed_by_wrong_relayer() {
+        let relayer: [u8; 32] = Bytes32::from_str(RANDOM_SALT2).unwrap().into();
+        let message_data = fuel_contract_message_predicate::relayer_message_data(
+            &env::test_contract_id(),
+            &relayer,
+            &[],
+        );
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        // Note: witness 0 is a valid signature over the transaction ID, but by the test wallet
+        let (wallet, _, contract_input, coin_inputs, message_inputs) =
+            env::setup_relayer_environment(vec![coin], vec![message], 0).await;
+
+        let _receipts = env::relay_message_to_contract(
+            &wallet,
+            message_inputs[0].clone(),
+            contract_input,
+            coin_inputs[0].clone(),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "The transaction contains a predicate which failed to validate")]
+    async fn relay_message_without_relayer_signature() {
+        let relayer: [u8; 32] = Address::from(env::test_wallet().address()).into();
+        let message_data = fuel_contract_message_predicate::relayer_message_data(
+            &env::test_contract_id(),
+            &relayer,
+            &[],
+        );
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        // Note: the transaction only has the witness of the gas coin
+        let (wallet, _, contract_input, coin_inputs, message_inputs) =
+            env::setup_relayer_environment(vec![coin], vec![message], 1).await;
+
+        let _receipts = env::relay_message_to_contract(
+            &wallet,
+            message_inputs[0].clone(),
+            contract_input,
+            coin_inputs[0].clone(),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "The transaction contains a predicate which failed to validate")]
+    async fn relay_message_by_other_relayer_in_relay_window() {
+        let deadline: u32 = 10;
+        let relayer: [u8; 32] = Bytes32::from_str(RANDOM_SALT2).unwrap().into();
+        let message_data = fuel_contract_message_predicate::relay_window_message_data(
+            &env::test_contract_id(),
+            &relayer,
+            u64::from(deadline),
+            &[],
+        );
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        // Note: witness 0 is a valid signature over the transaction ID, but by the test wallet
+        let (wallet, _, contract_input, coin_inputs, message_inputs) =
+            env::setup_relay_window_environment(vec![coin], vec![message], Some(0)).await;
+
+        let _receipts = env::relay_message_to_contract(
+            &wallet,
+            message_inputs[0].clone(),
+            contract_input,
+            coin_inputs[0].clone(),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "The transaction contains a predicate which failed to validate")]
+    async fn relay_message_with_maturity_below_relay_window_deadline() {
+        let deadline: u32 = 10;
+        let relayer: [u8; 32] = Bytes32::from_str(RANDOM_SALT2).unwrap().into();
+        let message_data = fuel_contract_message_predicate::relay_window_message_data(
+            &env::test_contract_id(),
+            &relayer,
+            u64::from(deadline),
+            &[],
+        );
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+
+        let (wallet, _, contract_input, coin_inputs, message_inputs) =
+            env::setup_relay_window_environment(vec![coin], vec![message], None).await;
+
+        let provider = wallet.provider().unwrap();
+        provider
+            .produce_blocks(u64::from(deadline), None)
+            .await
+            .unwrap();
+
+        // Note: the deadline is reached, but a lower maturity would allow an earlier relay
+        let (mut tx, _, _) = builder::build_contract_message_tx(
+            message_inputs[0].clone(),
+            &[contract_input, coin_inputs[0].clone()],
+            &[],
+            TxParameters::default().set_maturity(deadline - 1),
+        )
+        .await;
+
+        // Note: tx inputs[message, contract, coin], tx outputs[contract, change, variable]
+        let _receipts = env::sign_and_call_tx(&wallet, &mut tx).await;
     }
 }
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use fuel_asm::{op, GTFArgs, RegId};
 use fuel_tx::{Address, AssetId, Output};
 use fuels::{
     accounts::fuel_crypto::fuel_types::Word,
@@ -11,6 +12,7 @@ use fuels::{
         transaction_builders::{ScriptTransactionBuilder, TransactionBuilder},
     },
 };
+use sha2::{Digest, Sha256};
 
 /// Build a message-to-contract transaction with the given input coins and outputs
 /// note: unspent gas is returned to the owner of the first given gas input
@@ -20,9 +22,39 @@ pub async fn build_contract_message_tx(
     outputs: &[Output],
     params: TxParameters,
 ) -> (ScriptTransaction, Vec<Input>, Vec<Output>) {
-    // Get the script and predicate for contract messages
-    let script_bytecode = fuel_contract_message_predicate::script_bytecode();
+    build_message_tx_with_script(
+        fuel_contract_message_predicate::script_bytecode(),
+        message,
+        inputs,
+        outputs,
+        params,
+    )
+    .await
+}
+
+/// Build a transaction that runs the given script on a message with the given input coins and outputs
+/// note: unspent gas is returned to the owner of the first given gas input
+pub async fn build_message_tx_with_script(
+    script_bytecode: Vec<u8>,
+    message: Input,
+    inputs: &[Input],
+    outputs: &[Output],
+    params: TxParameters,
+) -> (ScriptTransaction, Vec<Input>, Vec<Output>) {
+    build_message_tx_with_script_data(script_bytecode, vec![], message, inputs, outputs, params)
+        .await
+}
 
+/// Build a transaction that runs the given script with the given script data on a message
+/// note: unspent gas is returned to the owner of the first given gas input
+pub async fn build_message_tx_with_script_data(
+    script_bytecode: Vec<u8>,
+    script_data: Vec<u8>,
+    message: Input,
+    inputs: &[Input],
+    outputs: &[Output],
+    params: TxParameters,
+) -> (ScriptTransaction, Vec<Input>, Vec<Output>) {
     // Start building list of inputs and outputs
     let mut tx_outputs: Vec<Output> = outputs.to_vec();
     let mut tx_inputs: Vec<Input> = vec![message];
@@ -67,9 +99,94 @@ pub async fn build_contract_message_tx(
         .set_outputs(tx_outputs.clone())
         .set_tx_params(params)
         .set_script(script_bytecode)
+        .set_script_data(script_data)
         .set_consensus_parameters(ConsensusParameters::default())
         .build()
         .unwrap();
 
     (script_tx, tx_inputs, tx_outputs)
 }
+
+/// Build a transaction that sweeps the given predicate coins and messages to the recovery address
+/// note: gas is paid from the swept base asset
+pub async fn build_sweep_tx(
+    inputs: &[Input],
+    recovery_address: Address,
+    params: TxParameters,
+) -> ScriptTransaction {
+    // Return the change of every swept asset to the recovery address
+    let mut assets: Vec<AssetId> = inputs
+        .iter()
+        .filter_map(|input| match input {
+            Input::ResourcePredicate {
+                resource: CoinType::Coin(coin),
+                ..
+            } => Some(coin.asset_id),
+            Input::ResourcePredicate {
+                resource: CoinType::Message(_),
+                ..
+            } => Some(AssetId::default()),
+            _ => None,
+        })
+        .collect();
+    assets.sort();
+    assets.dedup();
+    let outputs: Vec<Output> = assets
+        .into_iter()
+        .map(|asset_id| Output::Change {
+            to: recovery_address,
+            amount: 0,
+            asset_id,
+        })
+        .collect();
+
+    ScriptTransactionBuilder::default()
+        .set_inputs(inputs.to_vec())
+        .set_outputs(outputs)
+        .set_tx_params(params)
+        .set_script(fuel_contract_message_predicate::sweep_script_bytecode())
+        .set_consensus_parameters(ConsensusParameters::default())
+        .build()
+        .unwrap()
+}
+
+/// Builds a script that calls `process_message` on the message target twice,
+/// as if the same message were relayed a second time
+pub fn replay_script_bytecode() -> Vec<u8> {
+    let fn_sel_hash: [u8; 32] = Sha256::digest("process_message(u8)").into();
+
+    //register names
+    const REG_MEMORY_START_PTR: u8 = 0x10;
+    const REG_ASSET_PTR: u8 = REG_MEMORY_START_PTR;
+    const REG_DATA_PTR: u8 = 0x11;
+    const REG_DATA_FN_SEL_PTR: u8 = 0x12;
+    const REG_CONTRACT_ADDR_PTR: u8 = 0x13;
+    const REG_FN_SELECTOR_PTR: u8 = 0x14;
+
+    //referenced data start pointer
+    const REF_DATA_START_PTR: u16 = 11 * 4;
+
+    let mut script: Vec<u8> = vec![
+        op::move_(REG_MEMORY_START_PTR, RegId::SP),
+        op::cfei(32 + 32 + 8 + 8),
+        op::addi(REG_DATA_PTR, REG_MEMORY_START_PTR, 32),
+        op::addi(REG_DATA_FN_SEL_PTR, REG_DATA_PTR, 32 + 4),
+        op::gtf(
+            REG_CONTRACT_ADDR_PTR,
+            RegId::ZERO,
+            GTFArgs::InputMessageData.into(),
+        ),
+        op::addi(REG_FN_SELECTOR_PTR, RegId::IS, REF_DATA_START_PTR),
+        op::mcpi(REG_DATA_PTR, REG_CONTRACT_ADDR_PTR, 32),
+        op::mcpi(REG_DATA_FN_SEL_PTR, REG_FN_SELECTOR_PTR, 4),
+        //call twice without forwarding any coins
+        op::call(REG_DATA_PTR, RegId::ZERO, REG_ASSET_PTR, RegId::CGAS),
+        op::call(REG_DATA_PTR, RegId::ZERO, REG_ASSET_PTR, RegId::CGAS),
+        op::ret(RegId::ZERO),
+    ]
+    .into_iter()
+    .collect();
+
+    script.append(&mut fn_sel_hash[0..4].to_vec());
+    script
+}
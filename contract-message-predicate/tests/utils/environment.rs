@@ -3,52 +3,93 @@ use crate::builder;
 use std::{mem::size_of, num::ParseIntError, str::FromStr, vec};
 
 use fuels::{
-    accounts::{fuel_crypto::SecretKey, wallet::WalletUnlocked, Signer},
+    accounts::{fuel_crypto::SecretKey, wallet::WalletUnlocked, Account, Signer},
     prelude::{
         abigen, setup_custom_assets_coins, Address, AssetConfig, AssetId, Contract,
         LoadConfiguration, Provider, ScriptTransaction, TxParameters,
     },
-    test_helpers::{setup_single_message, setup_test_client},
+    test_helpers::{setup_single_message, setup_test_client, Config},
     tx::{Bytes32, Receipt},
     types::{
-        coin_type::CoinType, input::Input, message::Message, unresolved_bytes::UnresolvedBytes,
+        coin_type::CoinType, errors::Error, input::Input, message::Message,
+        unresolved_bytes::UnresolvedBytes,
     },
 };
 
-use fuel_tx::{ConsensusParameters, TxPointer, UtxoId, Word};
+use fuel_contract_message_predicate::{
+    allowlist::ContractAllowlist, layout_policy::TxLayoutPolicy, MessageReceiverVersion,
+};
+use fuel_tx::{Chargeable, ConsensusParameters, TransactionFee, TxPointer, UtxoId, Word};
 
-abigen!(Contract(
-    name = "TestContract",
-    abi = "./contract-message-predicate/out/debug/contract_message_test-abi.json"
-));
+abigen!(
+    Contract(
+        name = "TestContract",
+        abi = "./contract-message-predicate/out/debug/contract_message_test-abi.json"
+    ),
+    Contract(
+        name = "TestContractV2",
+        abi = "./contract-message-predicate/tests/contracts/contract_message_test_v2/out/debug/contract_message_test_v2-abi.json"
+    )
+);
 
 pub const MESSAGE_SENDER_ADDRESS: &str =
     "0xca400d3e7710eee293786830755278e6d2b9278b4177b8b1a896ebd5f55c10bc";
+pub const MESSAGE_SENDER_ADDRESS2: &str =
+    "0x5278e6d2b9278b4177b8b1a896ebd5f55c10bcca400d3e7710eee29378683075";
 pub const TEST_RECEIVER_CONTRACT_BINARY: &str = "./out/debug/contract_message_test.bin";
+pub const TEST_RECEIVER_V2_CONTRACT_BINARY: &str =
+    "./tests/contracts/contract_message_test_v2/out/debug/contract_message_test_v2.bin";
+pub const REVERTING_RECEIVER_CONTRACT_BINARY: &str =
+    "./tests/contracts/contract_message_reverting/out/debug/contract_message_reverting.bin";
 
-/// Sets up a test fuel environment with a funded wallet
-pub async fn setup_environment(
-    coins: Vec<(Word, AssetId)>,
-    messages: Vec<(Word, Vec<u8>)>,
-) -> (
+/// Revert code of a failed Sway `require`, whose error is logged just before the revert
+pub const FAILED_REQUIRE_SIGNAL: u64 = 0xffff_ffff_ffff_0000;
+
+/// Wallet, test contract, test contract input, coin inputs and message inputs of a test environment
+pub type TestEnvironment = (
     WalletUnlocked,
     TestContract<WalletUnlocked>,
     Input,
     Vec<Input>,
     Vec<Input>,
-) {
-    // Create secret for wallet
-    const SIZE_SECRET_KEY: usize = size_of::<SecretKey>();
-    const PADDING_BYTES: usize = SIZE_SECRET_KEY - size_of::<u64>();
-    let mut secret_key: [u8; SIZE_SECRET_KEY] = [0; SIZE_SECRET_KEY];
-    secret_key[PADDING_BYTES..].copy_from_slice(&(8320147306839812359u64).to_be_bytes());
+);
+
+/// Settings for the test environment created by `setup_environment_with`
+struct EnvironmentConfig {
+    /// L1 sender of the generated messages
+    pub message_sender: &'static str,
+    /// Predicate that owns the generated messages, or `None` for messages owned by the wallet
+    pub message_predicate: Option<Vec<u8>>,
+    /// Configurables used when deploying the test contract
+    pub configurables: TestContractConfigurables,
+}
 
+impl Default for EnvironmentConfig {
+    fn default() -> Self {
+        Self {
+            message_sender: MESSAGE_SENDER_ADDRESS,
+            message_predicate: Some(fuel_contract_message_predicate::predicate_bytecode()),
+            configurables: TestContractConfigurables::new(),
+        }
+    }
+}
+
+/// Sets up a test fuel environment with a funded wallet
+pub async fn setup_environment(
+    coins: Vec<(Word, AssetId)>,
+    messages: Vec<(Word, Vec<u8>)>,
+) -> TestEnvironment {
+    setup_environment_with(coins, messages, EnvironmentConfig::default()).await
+}
+
+/// Sets up a test fuel environment with a funded wallet and the given settings
+async fn setup_environment_with(
+    coins: Vec<(Word, AssetId)>,
+    messages: Vec<(Word, Vec<u8>)>,
+    config: EnvironmentConfig,
+) -> TestEnvironment {
     // Generate wallet
-    let mut wallet = WalletUnlocked::new_from_private_key(
-        SecretKey::try_from(secret_key.as_slice())
-            .expect("This should never happen as we provide a [u8; SIZE_SECRET_KEY] array"),
-        None,
-    );
+    let mut wallet = test_wallet();
 
     // Generate coins for wallet
     let asset_configs: Vec<AssetConfig> = coins
@@ -62,11 +103,13 @@ pub async fn setup_environment(
     let all_coins = setup_custom_assets_coins(wallet.address(), &asset_configs[..]);
 
     // Generate messages
-    let message_sender = Address::from_str(MESSAGE_SENDER_ADDRESS).unwrap();
-    let predicate_bytecode = fuel_contract_message_predicate::predicate_bytecode();
-    let predicate_root = Address::from(fuel_contract_message_predicate::predicate_root(
-        &ConsensusParameters::default(),
-    ));
+    let message_sender = Address::from_str(config.message_sender).unwrap();
+    let message_recipient = match &config.message_predicate {
+        Some(predicate) => {
+            fuel_tx::Input::predicate_owner(predicate, &ConsensusParameters::default())
+        }
+        None => wallet.address().into(),
+    };
 
     let all_messages: Vec<Message> = messages
         .iter()
@@ -74,7 +117,7 @@ pub async fn setup_environment(
         .flat_map(|(counter, message)| {
             vec![setup_single_message(
                 &message_sender.into(),
-                &predicate_root.into(),
+                &message_recipient.into(),
                 message.0,
                 (counter as u64).into(),
                 message.1.clone(),
@@ -83,20 +126,32 @@ pub async fn setup_environment(
         .collect();
 
     // Create the client and provider
-    let (client, _, consensus_params) =
-        setup_test_client(all_coins.clone(), all_messages.clone(), None, None).await;
+    // note: manual blocks let tests advance the block height
+    let node_config = Config {
+        manual_blocks_enabled: true,
+        ..Config::local_node()
+    };
+    let (client, _, consensus_params) = setup_test_client(
+        all_coins.clone(),
+        all_messages.clone(),
+        Some(node_config),
+        None,
+    )
+    .await;
     let provider = Provider::new(client, consensus_params);
 
     // Add provider to wallet
     wallet.set_provider(provider);
 
     // Deploy the target contract used for testing processing messages
-    let test_contract_id =
-        Contract::load_from(TEST_RECEIVER_CONTRACT_BINARY, LoadConfiguration::default())
-            .unwrap()
-            .deploy(&wallet, TxParameters::default())
-            .await
-            .unwrap();
+    let test_contract_id = Contract::load_from(
+        TEST_RECEIVER_CONTRACT_BINARY,
+        LoadConfiguration::default().set_configurables(config.configurables),
+    )
+    .unwrap()
+    .deploy(&wallet, TxParameters::default())
+    .await
+    .unwrap();
 
     let test_contract = TestContract::new(test_contract_id.clone(), wallet.clone());
 
@@ -109,12 +164,13 @@ pub async fn setup_environment(
     // Build inputs for provided messages
     let message_inputs: Vec<Input> = all_messages
         .into_iter()
-        .map(|message| {
-            Input::resource_predicate(
+        .map(|message| match &config.message_predicate {
+            Some(predicate) => Input::resource_predicate(
                 CoinType::Message(message),
-                predicate_bytecode.clone(),
+                predicate.clone(),
                 UnresolvedBytes::default(),
-            )
+            ),
+            None => Input::resource_signed(CoinType::Message(message), 0),
         })
         .collect();
 
@@ -136,6 +192,291 @@ pub async fn setup_environment(
     )
 }
 
+/// Sets up a test fuel environment with messages owned by the given predicate
+pub async fn setup_predicate_environment(
+    coins: Vec<(Word, AssetId)>,
+    messages: Vec<(Word, Vec<u8>)>,
+    predicate: Vec<u8>,
+) -> TestEnvironment {
+    setup_environment_with(
+        coins,
+        messages,
+        EnvironmentConfig {
+            message_predicate: Some(predicate),
+            ..Default::default()
+        },
+    )
+    .await
+}
+
+/// Sets up a test fuel environment with messages owned by the wallet
+pub async fn setup_signed_environment(
+    coins: Vec<(Word, AssetId)>,
+    messages: Vec<(Word, Vec<u8>)>,
+) -> TestEnvironment {
+    setup_environment_with(
+        coins,
+        messages,
+        EnvironmentConfig {
+            message_predicate: None,
+            ..Default::default()
+        },
+    )
+    .await
+}
+
+/// Sets up a test fuel environment with messages sent by `MESSAGE_SENDER_ADDRESS2` and owned by
+/// the given predicate, and a test contract deployed with the given configurables
+pub async fn setup_second_sender_environment(
+    coins: Vec<(Word, AssetId)>,
+    messages: Vec<(Word, Vec<u8>)>,
+    predicate: Vec<u8>,
+    configurables: TestContractConfigurables,
+) -> TestEnvironment {
+    setup_environment_with(
+        coins,
+        messages,
+        EnvironmentConfig {
+            message_sender: MESSAGE_SENDER_ADDRESS2,
+            message_predicate: Some(predicate),
+            configurables,
+        },
+    )
+    .await
+}
+
+/// Sets up a test fuel environment with messages owned by the predicate of the given receiver version
+pub async fn setup_version_environment(
+    coins: Vec<(Word, AssetId)>,
+    messages: Vec<(Word, Vec<u8>)>,
+    version: MessageReceiverVersion,
+) -> TestEnvironment {
+    setup_predicate_environment(coins, messages, version.predicate_bytecode()).await
+}
+
+/// Sets up a test fuel environment with messages owned by the refundable predicate
+pub async fn setup_refundable_environment(
+    coins: Vec<(Word, AssetId)>,
+    messages: Vec<(Word, Vec<u8>)>,
+) -> TestEnvironment {
+    let predicate = fuel_contract_message_predicate::refundable_predicate_bytecode();
+    setup_predicate_environment(coins, messages, predicate).await
+}
+
+/// Sets up a test fuel environment with messages owned by the time-locked predicate
+pub async fn setup_time_locked_environment(
+    coins: Vec<(Word, AssetId)>,
+    messages: Vec<(Word, Vec<u8>)>,
+) -> TestEnvironment {
+    let predicate = fuel_contract_message_predicate::time_locked_predicate_bytecode();
+    setup_predicate_environment(coins, messages, predicate).await
+}
+
+/// Sets up a test fuel environment with messages owned by the relayer fee predicate
+pub async fn setup_fee_environment(
+    coins: Vec<(Word, AssetId)>,
+    messages: Vec<(Word, Vec<u8>)>,
+) -> TestEnvironment {
+    let predicate = fuel_contract_message_predicate::fee_predicate_bytecode();
+    setup_predicate_environment(coins, messages, predicate).await
+}
+
+/// Sets up a test fuel environment with messages owned by the code root pinned predicate
+pub async fn setup_pinned_environment(
+    coins: Vec<(Word, AssetId)>,
+    messages: Vec<(Word, Vec<u8>)>,
+) -> TestEnvironment {
+    let predicate = fuel_contract_message_predicate::pinned_predicate_bytecode();
+    setup_predicate_environment(coins, messages, predicate).await
+}
+
+/// Sets up a test fuel environment with messages owned by the relay event predicate
+pub async fn setup_event_environment(
+    coins: Vec<(Word, AssetId)>,
+    messages: Vec<(Word, Vec<u8>)>,
+) -> TestEnvironment {
+    let predicate = fuel_contract_message_predicate::event_predicate_bytecode();
+    setup_predicate_environment(coins, messages, predicate).await
+}
+
+/// Sets up a test fuel environment with messages owned by the self-funded predicate
+pub async fn setup_self_funded_environment(
+    coins: Vec<(Word, AssetId)>,
+    messages: Vec<(Word, Vec<u8>)>,
+) -> TestEnvironment {
+    let predicate = fuel_contract_message_predicate::self_funded_predicate_bytecode();
+    setup_predicate_environment(coins, messages, predicate).await
+}
+
+/// Sets up a test fuel environment with messages owned by the sealed outputs predicate
+pub async fn setup_sealed_outputs_environment(
+    coins: Vec<(Word, AssetId)>,
+    messages: Vec<(Word, Vec<u8>)>,
+) -> TestEnvironment {
+    let predicate = fuel_contract_message_predicate::sealed_outputs_predicate_bytecode();
+    setup_predicate_environment(coins, messages, predicate).await
+}
+
+/// Sets up a test fuel environment with messages owned by the recoverable predicate of the given
+/// recovery address
+pub async fn setup_recoverable_environment(
+    coins: Vec<(Word, AssetId)>,
+    messages: Vec<(Word, Vec<u8>)>,
+    recovery_address: &[u8; 32],
+) -> TestEnvironment {
+    let predicate =
+        fuel_contract_message_predicate::recoverable_predicate_bytecode(recovery_address);
+    setup_predicate_environment(coins, messages, predicate).await
+}
+
+/// Sets up a test fuel environment with messages owned by the predicate of the given contract
+/// allowlist, with predicate data proving that the given contract is in the allowlist
+pub async fn setup_contract_allowlist_environment(
+    coins: Vec<(Word, AssetId)>,
+    messages: Vec<(Word, Vec<u8>)>,
+    allowlist: &ContractAllowlist,
+    contract_id: &[u8; 32],
+) -> TestEnvironment {
+    let proof = allowlist.proof(contract_id).unwrap().to_predicate_data();
+    let (wallet, test_contract, contract_input, coin_inputs, message_inputs) =
+        setup_predicate_environment(coins, messages, allowlist.predicate_bytecode()).await;
+    let message_inputs = message_inputs
+        .into_iter()
+        .map(|message| with_predicate_data(message, proof.clone()))
+        .collect();
+    (
+        wallet,
+        test_contract,
+        contract_input,
+        coin_inputs,
+        message_inputs,
+    )
+}
+
+/// Sets up a test fuel environment with messages owned by the predicate of the given layout policy
+pub async fn setup_layout_policy_environment(
+    coins: Vec<(Word, AssetId)>,
+    messages: Vec<(Word, Vec<u8>)>,
+    policy: &TxLayoutPolicy,
+) -> TestEnvironment {
+    setup_predicate_environment(coins, messages, policy.predicate_bytecode()).await
+}
+
+/// Sets up a test fuel environment with messages owned by the relayer-authorized predicate, with
+/// predicate data pointing at the relayer signature in the given witness
+pub async fn setup_relayer_environment(
+    coins: Vec<(Word, AssetId)>,
+    messages: Vec<(Word, Vec<u8>)>,
+    witness_index: u8,
+) -> TestEnvironment {
+    let predicate = fuel_contract_message_predicate::relayer_predicate_bytecode();
+    let (wallet, test_contract, contract_input, coin_inputs, message_inputs) =
+        setup_predicate_environment(coins, messages, predicate).await;
+    let predicate_data = fuel_contract_message_predicate::relayer_predicate_data(witness_index);
+    let message_inputs = message_inputs
+        .into_iter()
+        .map(|message| with_predicate_data(message, predicate_data.clone()))
+        .collect();
+    (
+        wallet,
+        test_contract,
+        contract_input,
+        coin_inputs,
+        message_inputs,
+    )
+}
+
+/// Sets up a test fuel environment with messages owned by the relay window predicate, with
+/// predicate data pointing at the relayer signature in the given witness, if any
+pub async fn setup_relay_window_environment(
+    coins: Vec<(Word, AssetId)>,
+    messages: Vec<(Word, Vec<u8>)>,
+    witness_index: Option<u8>,
+) -> TestEnvironment {
+    let predicate = fuel_contract_message_predicate::relay_window_predicate_bytecode();
+    let (wallet, test_contract, contract_input, coin_inputs, message_inputs) =
+        setup_predicate_environment(coins, messages, predicate).await;
+    let message_inputs = match witness_index {
+        Some(index) => {
+            let predicate_data = fuel_contract_message_predicate::relayer_predicate_data(index);
+            message_inputs
+                .into_iter()
+                .map(|message| with_predicate_data(message, predicate_data.clone()))
+                .collect()
+        }
+        None => message_inputs,
+    };
+    (
+        wallet,
+        test_contract,
+        contract_input,
+        coin_inputs,
+        message_inputs,
+    )
+}
+
+/// Generates the wallet of the test environment, without a provider
+pub fn test_wallet() -> WalletUnlocked {
+    // Create secret for wallet
+    const SIZE_SECRET_KEY: usize = size_of::<SecretKey>();
+    const PADDING_BYTES: usize = SIZE_SECRET_KEY - size_of::<u64>();
+    let mut secret_key: [u8; SIZE_SECRET_KEY] = [0; SIZE_SECRET_KEY];
+    secret_key[PADDING_BYTES..].copy_from_slice(&(8320147306839812359u64).to_be_bytes());
+
+    WalletUnlocked::new_from_private_key(
+        SecretKey::try_from(secret_key.as_slice())
+            .expect("This should never happen as we provide a [u8; SIZE_SECRET_KEY] array"),
+        None,
+    )
+}
+
+/// Deploys the v2 receiver contract used for testing and builds an input for it
+pub async fn deploy_test_contract_v2(
+    wallet: &WalletUnlocked,
+) -> (TestContractV2<WalletUnlocked>, Input) {
+    let contract_id = Contract::load_from(
+        TEST_RECEIVER_V2_CONTRACT_BINARY,
+        LoadConfiguration::default(),
+    )
+    .unwrap()
+    .deploy(wallet, TxParameters::default())
+    .await
+    .unwrap();
+
+    let contract_input = Input::Contract {
+        utxo_id: UtxoId::new(Bytes32::from([0u8; 32]), 0),
+        balance_root: Bytes32::zeroed(),
+        state_root: Bytes32::zeroed(),
+        tx_pointer: TxPointer::default(),
+        contract_id: contract_id.clone().into(),
+    };
+
+    (
+        TestContractV2::new(contract_id, wallet.clone()),
+        contract_input,
+    )
+}
+
+/// Deploys the receiver contract that always reverts and builds an input for it
+pub async fn deploy_reverting_contract(wallet: &WalletUnlocked) -> Input {
+    let contract_id = Contract::load_from(
+        REVERTING_RECEIVER_CONTRACT_BINARY,
+        LoadConfiguration::default(),
+    )
+    .unwrap()
+    .deploy(wallet, TxParameters::default())
+    .await
+    .unwrap();
+
+    Input::Contract {
+        utxo_id: UtxoId::new(Bytes32::from([0u8; 32]), 0),
+        balance_root: Bytes32::zeroed(),
+        state_root: Bytes32::zeroed(),
+        tx_pointer: TxPointer::default(),
+        contract_id: contract_id.into(),
+    }
+}
+
 /// Relays a message-to-contract message
 pub async fn relay_message_to_contract(
     wallet: &WalletUnlocked,
@@ -156,23 +497,148 @@ pub async fn relay_message_to_contract(
     sign_and_call_tx(wallet, &mut tx).await
 }
 
+/// Relays a message-to-contract message, returning the error if the relay fails
+pub async fn try_relay_message_to_contract(
+    wallet: &WalletUnlocked,
+    message: Input,
+    contract: Input,
+    gas_coin: Input,
+) -> Result<Vec<Receipt>, Error> {
+    // Build transaction
+    let (mut tx, _, _) = builder::build_contract_message_tx(
+        message,
+        &vec![contract, gas_coin],
+        &[],
+        TxParameters::default(),
+    )
+    .await;
+
+    // Sign transaction and call
+    try_sign_and_call_tx(wallet, &mut tx).await
+}
+
 /// Relays a message-to-contract message
 pub async fn sign_and_call_tx(wallet: &WalletUnlocked, tx: &mut ScriptTransaction) -> Vec<Receipt> {
+    try_sign_and_call_tx(wallet, tx).await.unwrap()
+}
+
+/// Signs and sends a transaction, returning the error if it is rejected or reverts
+pub async fn try_sign_and_call_tx(
+    wallet: &WalletUnlocked,
+    tx: &mut ScriptTransaction,
+) -> Result<Vec<Receipt>, Error> {
     // Get provider and client
     let provider = wallet.provider().unwrap();
 
     // Sign transaction and call
     wallet.sign_transaction(tx).unwrap();
-    provider.send_transaction(tx).await.unwrap()
+    provider.send_transaction(tx).await
+}
+
+/// Gets the revert code and the receipts of a transaction that reverted
+pub fn revert_receipts(error: Error) -> (u64, Vec<Receipt>) {
+    match error {
+        Error::RevertTransactionError {
+            revert_id,
+            receipts,
+            ..
+        } => (revert_id, receipts),
+        error => panic!("Expected the transaction to revert, got: {error}"),
+    }
+}
+
+/// Gets the fee charged for an executed transaction, the max fee minus the refund of unused gas
+pub fn charged_fee(tx: &ScriptTransaction, receipts: &[Receipt]) -> u64 {
+    let params = ConsensusParameters::default();
+    let max_fee = TransactionFee::checked_from_tx(&params, &tx.tx)
+        .unwrap()
+        .total();
+    let gas_used = receipts
+        .iter()
+        .find_map(|receipt| match receipt {
+            Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+            _ => None,
+        })
+        .unwrap();
+    let refund =
+        TransactionFee::gas_refund_value(&params, tx.tx.limit() - gas_used, tx.tx.price()).unwrap();
+    max_fee - refund
+}
+
+/// Gets the ID of the test contract deployed with default configurables
+pub fn test_contract_id() -> [u8; 32] {
+    Contract::load_from(TEST_RECEIVER_CONTRACT_BINARY, LoadConfiguration::default())
+        .unwrap()
+        .contract_id()
+        .into()
+}
+
+/// Gets the ID of the receiver contract that always reverts
+pub fn reverting_contract_id() -> [u8; 32] {
+    Contract::load_from(
+        REVERTING_RECEIVER_CONTRACT_BINARY,
+        LoadConfiguration::default(),
+    )
+    .unwrap()
+    .contract_id()
+    .into()
+}
+
+/// Transfers base asset from the wallet to the root of the given predicate and builds an input
+/// for the new coin
+pub async fn transfer_to_predicate(
+    wallet: &WalletUnlocked,
+    predicate: Vec<u8>,
+    amount: Word,
+) -> Input {
+    let provider = wallet.provider().unwrap();
+    let predicate_root: Address =
+        fuel_tx::Input::predicate_owner(&predicate, &ConsensusParameters::default());
+    let _receipts = wallet
+        .transfer(
+            &predicate_root.into(),
+            amount,
+            AssetId::default(),
+            TxParameters::default(),
+        )
+        .await
+        .unwrap();
+    let coin = provider
+        .get_coins(&predicate_root.into(), AssetId::default())
+        .await
+        .unwrap()
+        .remove(0);
+
+    Input::resource_predicate(CoinType::Coin(coin), predicate, UnresolvedBytes::default())
+}
+
+/// Replaces the predicate data of the given predicate message input
+pub fn with_predicate_data(message: Input, data: Vec<u8>) -> Input {
+    match message {
+        Input::ResourcePredicate { resource, code, .. } => {
+            Input::resource_predicate(resource, code, UnresolvedBytes::new(data))
+        }
+        _ => panic!("Expected a predicate input"),
+    }
 }
 
 /// Prefixes the given bytes with the test contract ID
-pub async fn prefix_contract_id(mut data: Vec<u8>) -> Vec<u8> {
+pub async fn prefix_contract_id(data: Vec<u8>) -> Vec<u8> {
+    prefix_configured_contract_id(data, TestContractConfigurables::new()).await
+}
+
+/// Prefixes the given bytes with the ID of the test contract deployed with the given configurables
+pub async fn prefix_configured_contract_id(
+    mut data: Vec<u8>,
+    configurables: TestContractConfigurables,
+) -> Vec<u8> {
     // Compute the test contract ID
-    let test_contract_id =
-        Contract::load_from(TEST_RECEIVER_CONTRACT_BINARY, LoadConfiguration::default())
-            .unwrap()
-            .contract_id();
+    let test_contract_id = Contract::load_from(
+        TEST_RECEIVER_CONTRACT_BINARY,
+        LoadConfiguration::default().set_configurables(configurables),
+    )
+    .unwrap()
+    .contract_id();
 
     // Turn contract id into array with the given data appended to it
     let mut test_contract_id = test_contract_id.to_vec();
@@ -180,6 +646,32 @@ pub async fn prefix_contract_id(mut data: Vec<u8>) -> Vec<u8> {
     test_contract_id
 }
 
+/// Prefixes the given bytes with the v2 test contract ID
+pub async fn prefix_contract_v2_id(mut data: Vec<u8>) -> Vec<u8> {
+    let test_contract_id = Contract::load_from(
+        TEST_RECEIVER_V2_CONTRACT_BINARY,
+        LoadConfiguration::default(),
+    )
+    .unwrap()
+    .contract_id();
+
+    let mut test_contract_id = test_contract_id.to_vec();
+    test_contract_id.append(&mut data);
+    test_contract_id
+}
+
+/// Constructs refundable message data targeting the receiver contract that always reverts
+pub async fn refundable_message_data(refund_address: &str, refund_height: u64) -> Vec<u8> {
+    let refund_address = Address::from_str(refund_address).unwrap();
+
+    fuel_contract_message_predicate::refundable_message_data(
+        &reverting_contract_id(),
+        &refund_address.into(),
+        refund_height,
+        &[],
+    )
+}
+
 /// Quickly converts the given hex string into a u8 vector
 pub fn decode_hex(s: &str) -> Vec<u8> {
     let data: core::result::Result<Vec<u8>, ParseIntError> = (2..s.len())
@@ -196,3 +688,19 @@ pub async fn message_data(word: u64, bytes: &str, address: &str) -> Vec<u8> {
     message_data.append(&mut decode_hex(address));
     prefix_contract_id(message_data).await
 }
+
+/// Constructs test message data that exercises every type the receiver library can read
+pub async fn full_message_data(
+    word: u64,
+    bytes: &str,
+    address: &str,
+    byte: u8,
+    short: u16,
+    tail: &[u8],
+) -> Vec<u8> {
+    let mut message_data = message_data(word, bytes, address).await;
+    message_data.push(byte);
+    message_data.append(&mut short.to_be_bytes().to_vec());
+    message_data.append(&mut tail.to_vec());
+    message_data
+}
@@ -0,0 +1,49 @@
+#![cfg(target_arch = "wasm32")]
+
+use fuel_contract_message_predicate::wasm;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+// Values computed by the native (std) build, shared with the Python and C binding tests
+const FIXTURE: &str = include_str!("fixtures/bindings.txt");
+
+/// Gets the value of the given key in the bindings fixture
+fn expected(key: &str) -> &'static str {
+    FIXTURE
+        .lines()
+        .find_map(|line| line.strip_prefix(key)?.strip_prefix('='))
+        .unwrap_or_else(|| panic!("Missing {key} in the bindings fixture"))
+}
+
+#[wasm_bindgen_test]
+fn script_hash_matches_native_build() {
+    assert_eq!(hex::encode(wasm::script_hash()), expected("SCRIPT_HASH"));
+}
+
+#[wasm_bindgen_test]
+fn predicate_root_matches_native_build() {
+    assert_eq!(
+        hex::encode(wasm::predicate_root(0)),
+        expected("PREDICATE_ROOT")
+    );
+    assert_eq!(
+        hex::encode(wasm::predicate_root(1)),
+        expected("PREDICATE_ROOT_CHAIN_ID_1")
+    );
+}
+
+#[wasm_bindgen_test]
+fn predicate_commits_to_script() {
+    let predicate = wasm::predicate_bytecode();
+    let script_hash = wasm::script_hash();
+    assert_eq!(predicate[predicate.len() - 32..], script_hash[..]);
+    assert!(!wasm::script_bytecode().is_empty());
+}
+
+#[wasm_bindgen_test]
+fn encodes_message_data() {
+    let contract_id = hex::decode(expected("MESSAGE_DATA_CONTRACT_ID")).unwrap();
+    let payload = hex::decode(expected("MESSAGE_DATA_PAYLOAD")).unwrap();
+    let data = wasm::message_data(&contract_id, &payload).unwrap();
+    assert_eq!(hex::encode(data), expected("MESSAGE_DATA"));
+    assert!(wasm::message_data(&contract_id[..31], &[]).is_err());
+}
@@ -1,7 +1,23 @@
 library;
 
+mod errors;
+mod message;
+mod message_data;
+mod processed_messages;
+
 abi MessageReceiver {
     #[storage(read, write)]
     #[payable]
     fn process_message(msg_idx: u8);
 }
+
+/// Version 2 of the receiver ABI, called by the v2 message-to-contract script.
+///
+/// `data_ptr` and `data_len` locate the full message data (starting with the
+/// target contract ID) in transaction memory. Receivers should check the arguments
+/// with `message::require_relayed_message` before trusting them.
+abi MessageReceiverV2 {
+    #[storage(read, write)]
+    #[payable]
+    fn process_message_v2(sender: b256, amount: u64, nonce: b256, data_ptr: u64, data_len: u64);
+}
@@ -0,0 +1,17 @@
+library;
+
+/// Errors logged by the message receiver helpers when a check fails.
+pub enum MessageReceiverError {
+    /// The input at the given index is not an `InputMessage`.
+    NotAMessage: (),
+    /// The message data does not target the contract processing it.
+    WrongTarget: (),
+    /// A read went past the end of the message data.
+    DataOutOfBounds: (),
+    /// The message was not sent by the trusted L1 sender.
+    UntrustedSender: (),
+    /// The message has already been processed.
+    AlreadyProcessed: (),
+    /// The arguments passed to the receiver do not describe the relayed message.
+    MessageMismatch: (),
+}
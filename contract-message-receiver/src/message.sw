@@ -0,0 +1,144 @@
+library;
+
+use ::errors::MessageReceiverError;
+use std::alloc::alloc_bytes;
+use std::call_frames::contract_id;
+use std::constants::ZERO_B256;
+use std::inputs::{
+    Input,
+    input_message_data,
+    input_message_data_length,
+    input_message_sender,
+    input_type,
+};
+
+const GTF_INPUT_MESSAGE_SENDER = 0x115;
+const GTF_INPUT_MESSAGE_RECIPIENT = 0x116;
+const GTF_INPUT_MESSAGE_AMOUNT = 0x117;
+const GTF_INPUT_MESSAGE_NONCE = 0x118;
+const GTF_INPUT_MESSAGE_DATA_LENGTH = 0x11A;
+const GTF_INPUT_MESSAGE_DATA = 0x11D;
+
+/// Returns true if the input at `msg_idx` is an `InputMessage`.
+pub fn is_message(msg_idx: u8) -> bool {
+    match input_type(msg_idx) {
+        Input::Message => true,
+        _ => false,
+    }
+}
+
+/// Gets the L1 sender of the message at `msg_idx`.
+pub fn message_sender(msg_idx: u8) -> Address {
+    require(is_message(msg_idx), MessageReceiverError::NotAMessage);
+    input_message_sender(msg_idx)
+}
+
+/// Gets the base asset amount carried by the message at `msg_idx`.
+pub fn message_amount(msg_idx: u8) -> u64 {
+    require(is_message(msg_idx), MessageReceiverError::NotAMessage);
+    __gtf::<u64>(msg_idx, GTF_INPUT_MESSAGE_AMOUNT)
+}
+
+/// Gets the contract targeted by the message at `msg_idx` (the first 32 bytes of its data).
+pub fn message_target(msg_idx: u8) -> ContractId {
+    require(is_message(msg_idx), MessageReceiverError::NotAMessage);
+    require(
+        input_message_data_length(msg_idx) >= 32u16,
+        MessageReceiverError::DataOutOfBounds,
+    );
+    let target: b256 = input_message_data(msg_idx, 0).into();
+    ContractId::from(target)
+}
+
+/// Returns true if the message at `msg_idx` targets the calling contract.
+pub fn is_message_for_this_contract(msg_idx: u8) -> bool {
+    if !is_message(msg_idx) || input_message_data_length(msg_idx) < 32u16 {
+        return false;
+    }
+    message_target(msg_idx) == contract_id()
+}
+
+/// Computes the ID of the message at `msg_idx`.
+///
+/// This is the same ID fuel-tx computes for a `Message`:
+/// sha256(sender, recipient, nonce, amount, data)
+pub fn message_id(msg_idx: u8) -> b256 {
+    require(is_message(msg_idx), MessageReceiverError::NotAMessage);
+    let data_length = __gtf::<u64>(msg_idx, GTF_INPUT_MESSAGE_DATA_LENGTH);
+    let length = 32 + 32 + 32 + 8 + data_length;
+
+    let sender = __gtf::<raw_ptr>(msg_idx, GTF_INPUT_MESSAGE_SENDER);
+    let recipient = __gtf::<raw_ptr>(msg_idx, GTF_INPUT_MESSAGE_RECIPIENT);
+    let nonce = __gtf::<raw_ptr>(msg_idx, GTF_INPUT_MESSAGE_NONCE);
+    let amount = __gtf::<u64>(msg_idx, GTF_INPUT_MESSAGE_AMOUNT);
+    let data = __gtf::<raw_ptr>(msg_idx, GTF_INPUT_MESSAGE_DATA);
+
+    // Lay out the preimage in the same order as fuel-tx
+    let preimage = alloc_bytes(length);
+    sender.copy_bytes_to(preimage, 32);
+    recipient.copy_bytes_to(preimage.add_uint_offset(32), 32);
+    nonce.copy_bytes_to(preimage.add_uint_offset(64), 32);
+    preimage.add_uint_offset(96).write(amount);
+    data.copy_bytes_to(preimage.add_uint_offset(104), data_length);
+
+    let mut id = ZERO_B256;
+    asm(id: id, ptr: preimage, len: length) {
+        s256 id ptr len;
+        id: b256
+    }
+}
+
+/// Returns true if the message at `msg_idx` was sent by `sender` on L1.
+pub fn is_message_from(msg_idx: u8, sender: Address) -> bool {
+    is_message(msg_idx) && input_message_sender(msg_idx) == sender
+}
+
+/// Reverts unless the message at `msg_idx` was sent by `trusted_sender` on L1.
+pub fn require_trusted_sender(msg_idx: u8, trusted_sender: Address) {
+    require(is_message(msg_idx), MessageReceiverError::NotAMessage);
+    require(
+        is_message_from(msg_idx, trusted_sender),
+        MessageReceiverError::UntrustedSender,
+    );
+}
+
+/// Reverts unless the input at `msg_idx` is a message that targets the calling contract.
+pub fn require_message_for_this_contract(msg_idx: u8) {
+    require(is_message(msg_idx), MessageReceiverError::NotAMessage);
+    require(is_message_for_this_contract(msg_idx), MessageReceiverError::WrongTarget);
+}
+
+/// Reverts unless the arguments passed to `process_message_v2` describe the message
+/// at input 0 and that message targets the calling contract.
+///
+/// The v2 script always relays input 0, so this stops other scripts from calling
+/// `process_message_v2` with made up arguments.
+pub fn require_relayed_message(
+    sender: b256,
+    amount: u64,
+    nonce: b256,
+    data_ptr: u64,
+    data_len: u64,
+) {
+    require_message_for_this_contract(0);
+    require(
+        __gtf::<b256>(0, GTF_INPUT_MESSAGE_SENDER) == sender,
+        MessageReceiverError::MessageMismatch,
+    );
+    require(
+        __gtf::<u64>(0, GTF_INPUT_MESSAGE_AMOUNT) == amount,
+        MessageReceiverError::MessageMismatch,
+    );
+    require(
+        __gtf::<b256>(0, GTF_INPUT_MESSAGE_NONCE) == nonce,
+        MessageReceiverError::MessageMismatch,
+    );
+    require(
+        __gtf::<u64>(0, GTF_INPUT_MESSAGE_DATA) == data_ptr,
+        MessageReceiverError::MessageMismatch,
+    );
+    require(
+        __gtf::<u64>(0, GTF_INPUT_MESSAGE_DATA_LENGTH) == data_len,
+        MessageReceiverError::MessageMismatch,
+    );
+}
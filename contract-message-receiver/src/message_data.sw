@@ -0,0 +1,123 @@
+library;
+
+use ::errors::MessageReceiverError;
+use std::bytes::Bytes;
+use std::inputs::input_message_data;
+
+/// A cursor over the data of an `InputMessage`.
+///
+/// Values are read in order starting from the first byte of the message data,
+/// which is the ID of the target contract. Numbers are read big-endian.
+pub struct MessageDataReader {
+    data: Bytes,
+    offset: u64,
+}
+
+impl MessageDataReader {
+    /// Creates a reader positioned at the start of the data of the message at `msg_idx`.
+    pub fn new(msg_idx: u8) -> Self {
+        MessageDataReader {
+            data: input_message_data(msg_idx, 0),
+            offset: 0,
+        }
+    }
+
+    /// Creates a reader over `length` bytes of message data at `ptr`, as passed to
+    /// `process_message_v2`.
+    pub fn from_raw_parts(ptr: u64, length: u64) -> Self {
+        let ptr = asm(ptr: ptr) {
+            ptr: raw_ptr
+        };
+        let mut data = Bytes::with_capacity(length);
+        let mut i = 0;
+        while i < length {
+            let byte_ptr = ptr.add_uint_offset(i);
+            data.push(asm(ptr: byte_ptr, r0) {
+                lb   r0 ptr i0;
+                r0: u8
+            });
+            i += 1;
+        }
+        MessageDataReader {
+            data: data,
+            offset: 0,
+        }
+    }
+
+    /// Gets the number of bytes that have not been read yet.
+    pub fn remaining(self) -> u64 {
+        self.data.len() - self.offset
+    }
+
+    /// Advances the reader by `count` bytes.
+    pub fn skip(ref mut self, count: u64) {
+        require(self.remaining() >= count, MessageReceiverError::DataOutOfBounds);
+        self.offset += count;
+    }
+
+    pub fn read_u8(ref mut self) -> u8 {
+        require(self.remaining() >= 1, MessageReceiverError::DataOutOfBounds);
+        let value = self.data.get(self.offset).unwrap();
+        self.offset += 1;
+        value
+    }
+
+    pub fn read_u16(ref mut self) -> u16 {
+        require(self.remaining() >= 2, MessageReceiverError::DataOutOfBounds);
+        let ptr = self.data.buf.ptr.add_uint_offset(self.offset);
+        self.offset += 2;
+        asm(ptr: ptr, hi, lo, r0) {
+            lb   hi ptr i0;
+            lb   lo ptr i1;
+            slli hi hi i8;
+            or   r0 hi lo;
+            r0: u16
+        }
+    }
+
+    pub fn read_u64(ref mut self) -> u64 {
+        require(self.remaining() >= 8, MessageReceiverError::DataOutOfBounds);
+        let ptr = self.data.buf.ptr.add_uint_offset(self.offset);
+        self.offset += 8;
+        asm(ptr: ptr, r0) {
+            lw   r0 ptr i0;
+            r0: u64
+        }
+    }
+
+    pub fn read_b256(ref mut self) -> b256 {
+        require(self.remaining() >= 32, MessageReceiverError::DataOutOfBounds);
+        let ptr = self.data.buf.ptr.add_uint_offset(self.offset);
+        self.offset += 32;
+        asm(ptr: ptr) {
+            ptr: b256
+        }
+    }
+
+    pub fn read_address(ref mut self) -> Address {
+        Address::from(self.read_b256())
+    }
+
+    pub fn read_contract_id(ref mut self) -> ContractId {
+        ContractId::from(self.read_b256())
+    }
+
+    /// Reads the next `length` bytes.
+    pub fn read_bytes(ref mut self, length: u64) -> Bytes {
+        require(self.remaining() >= length, MessageReceiverError::DataOutOfBounds);
+        let mut bytes = Bytes::with_capacity(length);
+        let mut i = 0;
+        while i < length {
+            bytes.push(self.data.get(self.offset + i).unwrap());
+            i += 1;
+        }
+        self.offset += length;
+        bytes
+    }
+
+    /// Reads all bytes that have not been read yet.
+    pub fn read_remaining(ref mut self) -> Bytes {
+        let length = self.remaining();
+        self.read_bytes(length)
+    }
+}
@@ -0,0 +1,22 @@
+library;
+
+use ::errors::MessageReceiverError;
+
+/// Returns true if the message with the given ID has been marked as processed.
+#[storage(read)]
+pub fn is_processed(processed: StorageKey<StorageMap<b256, bool>>, message_id: b256) -> bool {
+    processed.get(message_id).try_read().unwrap_or(false)
+}
+
+/// Marks the message with the given ID as processed.
+///
+/// Reverts if the message was already processed, so receivers that call this
+/// at the start of `process_message` handle each message exactly once.
+#[storage(read, write)]
+pub fn mark_processed(processed: StorageKey<StorageMap<b256, bool>>, message_id: b256) {
+    require(
+        !is_processed(processed, message_id),
+        MessageReceiverError::AlreadyProcessed,
+    );
+    processed.insert(message_id, true);
+}